@@ -7,7 +7,7 @@ use chrono::{DateTime, NaiveDateTime, TimeZone, Utc};
 use serde::{Deserialize, Deserializer, Serialize};
 use std::{fs::File, io::BufReader, sync::Arc};
 
-/**
+/*
 * /*
        CREATE TABLE user_stats(
      email varchar(128) NOT NULL PRIMARY KEY,
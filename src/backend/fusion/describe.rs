@@ -1,28 +1,371 @@
 use core::fmt;
-use std::sync::Arc;
+use std::{
+    collections::HashMap,
+    future::Future,
+    io::Write,
+    pin::Pin,
+    str::FromStr,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
 
-use arrow::datatypes::{DataType, Field};
+use anyhow::Context;
+use arrow::{
+    array::{
+        Array, ArrayRef, BooleanArray, Float64Array, Int32Array, Int64Array, MapArray, RecordBatch,
+        StringArray,
+    },
+    compute::{cast as cast_array, concat, concat_batches},
+    datatypes::{DataType, Field, Schema},
+    ipc::writer::StreamWriter,
+    util::display::array_value_to_string,
+};
+use chrono::{DateTime, Utc};
+use datafusion::logical_expr::{approx_percentile_cont, approx_percentile_cont_with_weight};
 use datafusion::{
+    common::JoinType,
     dataframe::DataFrame,
-    functions::expr_fn::length,
+    datasource::{listing::ListingTableUrl, MemTable},
+    execution::context::SessionContext,
+    functions::expr_fn::{
+        abs, btrim, coalesce, concat_ws, encode, get_field, length, named_struct, regexp_like,
+        rtrim, split_part, sqrt,
+    },
+    functions_aggregate::expr_fn::covar_samp,
     functions_array::length::array_length,
-    logical_expr::{avg, case, cast, col, count, is_null, lit, max, median, min, stddev, sum},
+    logical_expr::{
+        aggregate_function, avg, case, cast, col, count, count_distinct, create_udf, expr, ident,
+        is_null, lit, max, median, min, stddev, sum, try_cast,
+        type_coercion::functions::data_types, when, AggregateUDF, BuiltInWindowFunction,
+        ColumnarValue, Expr, LogicalPlan, ScalarUDF, TableSource, Volatility, WindowFrame,
+        WindowFunctionDefinition,
+    },
+    physical_plan::{collect, displayable, ExecutionPlan},
+    scalar::ScalarValue,
+};
+#[cfg(feature = "deltalake")]
+use deltalake::{
+    kernel::{Action, Protocol, StructType},
+    protocol::SaveMode,
+    writer::{DeltaWriter, RecordBatchWriter},
+    DeltaOps,
+};
+use futures::{stream, Stream};
+use object_store::{
+    aws::AmazonS3Builder, azure::MicrosoftAzureBuilder, gcp::GoogleCloudStorageBuilder, ObjectStore,
 };
-use datafusion::logical_expr::approx_percentile_cont;
+use rand_distr::{Distribution, Normal};
+use serde_json::{json, Map, Value};
+
+/// A pinned, boxed, `Send` future, like `futures::future::BoxFuture`.
+pub type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
+
+/// Identifies a `describe` plan, see [`DataFrameDescriber::try_new_with_cache`].
+pub type PlanFingerprint = u64;
+
+/// `describe` results shared between describers, keyed by plan fingerprint.
+pub type DescribeCache = Arc<Mutex<HashMap<PlanFingerprint, CachedDescribe>>>;
+
+/// A `describe` result in a [`DescribeCache`].
+#[derive(Clone)]
+pub struct CachedDescribe {
+    /// The tables the plan scans. Tables are told apart by address, so the entry
+    /// keeps them alive: a dropped table's address could be reused by a new one.
+    sources: Vec<Arc<dyn TableSource>>,
+    pub batches: Vec<RecordBatch>,
+}
+
+impl fmt::Debug for CachedDescribe {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CachedDescribe")
+            .field("sources", &self.sources.len())
+            .field("batches", &self.batches)
+            .finish()
+    }
+}
+
+impl CachedDescribe {
+    /// Whether the entry was computed from exactly the tables `sources`.
+    fn scans(&self, sources: &[Arc<dyn TableSource>]) -> bool {
+        self.sources.len() == sources.len()
+            && self
+                .sources
+                .iter()
+                .zip(sources)
+                .all(|(a, b)| Arc::ptr_eq(a, b))
+    }
+}
 
+/// Maps a field of the described DataFrame to the numeric expression the
+/// statistics are computed over, see [`DataFrameDescriber::try_new_with_custom_cast`].
+pub type CastFn = Arc<dyn Fn(&Field) -> Expr + Send + Sync>;
 
+#[derive(Clone)]
+struct CustomCast(CastFn);
+
+impl fmt::Debug for CustomCast {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("CustomCast")
+    }
+}
+
+/// Rewrites the described DataFrame before statistics are computed, see
+/// [`DataFrameDescriber::apply_before_describe`].
+pub type PreTransformFn = Arc<dyn Fn(DataFrame) -> anyhow::Result<DataFrame> + Send + Sync>;
+
+#[derive(Clone)]
+struct PreTransform(PreTransformFn);
+
+impl fmt::Debug for PreTransform {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("PreTransform")
+    }
+}
+
+/// Column names hinting at personal data.
+const SENSITIVE_NAMES: &[&str] = &["email", "phone", "ssn", "dob"];
+/// Distinct-to-count ratio above which a string column looks like an identifier.
+const HIGH_CARDINALITY_RATIO: f64 = 0.9;
+/// Share of parseable values above which a string column looks numeric.
+const NUMERIC_STRING_RATIO: f64 = 0.9;
+/// Rows sampled by `detect_mixed_type_string_columns`.
+const MIXED_TYPE_SAMPLE_ROWS: usize = 10_000;
+/// Null fraction below which a column is expected to be non-null.
+const LOW_NULL_RATIO: f64 = 0.05;
+/// Methods matching the rows of pandas' `DataFrame.describe()`, in its order.
+const PANDAS_METHODS: &[(DescribeMethod, &str)] = &[
+    (DescribeMethod::Total, "count"),
+    (DescribeMethod::Mean, "mean"),
+    (DescribeMethod::Stddev, "std"),
+    (DescribeMethod::Min, "min"),
+    (DescribeMethod::Percentile(25), "25%"),
+    (DescribeMethod::Percentile(50), "50%"),
+    (DescribeMethod::Percentile(75), "75%"),
+    (DescribeMethod::Max, "max"),
+];
+/// Object store url schemes accepted by `describe_remote`.
+const REMOTE_SCHEMES: &[&str] = &["s3", "gs", "az"];
+/// Resolution of percentiles in `DescribeMethod::sort_key`: 1000 orders
+/// percentiles down to 0.001 percent.
+const PERCENTILE_SORT_SCALE: f64 = 1000.0;
+/// Internal name of the row weight column of a weighted describer.
+const WEIGHT_COLUMN: &str = "__weight";
+/// Version of the `describe` output layout, bumped when methods, labels or
+/// column types change in a way persisted outputs can tell apart.
+const SCHEMA_VERSION: &str = "1";
+/// `describe` label of the row added by `with_dataset_summary`.
+const DATASET_SUMMARY_LABEL: &str = "__dataset__";
+/// Columns added by `with_dataset_summary`, null but in its row.
+const DATASET_SUMMARY_COLUMNS: [&str; 4] = [
+    "__dataset_rows",
+    "__dataset_columns",
+    "__dataset_cells",
+    "__dataset_null_percentage",
+];
+/// Arrow schema metadata key `SCHEMA_VERSION` is stored under, see
+/// [`DataFrameDescriber::with_version_metadata`].
+pub const SCHEMA_VERSION_KEY: &str = "describe.schema_version";
 
 #[allow(unused)]
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub enum DescribeMethod {
     Total,
     NullTotal,
     Mean,
     Stddev,
+    /// The sample standard deviation like `Stddev`, labeled to tell it apart
+    /// from `StddevPop` when both are reported
+    StddevSample,
+    /// The population standard deviation, dividing by `n` rather than `n - 1`
+    StddevPop,
     Min,
     Max,
     Median,
     Percentile(u8),
+    /// Percentile as a fraction in `[0, 1]`, e.g. `0.999`
+    PercentileF(f64),
+    /// The value the top `p` percent are above, i.e. the `100 - p` percentile
+    TopPercentile(u8),
+    IsConstant,
+    WhitespacePaddedCount,
+    Skewness,
+    MaxDecimalPlaces,
+    /// `min(abs(col))`: the magnitude closest to zero
+    MinAbs,
+    /// `max(abs(col))`: the largest magnitude
+    MaxAbs,
+    /// Gini coefficient in `[0, 1]`, null for columns with negative values
+    Gini,
+    /// Gini impurity `1 - sum(p_i^2)` of the distinct values' relative frequencies `p_i`:
+    /// 0 for a constant column, approaching 1 as all values become distinct
+    GiniImpurity,
+    /// Number of empty strings; together with `NullTotal` and `NonEmptyCount`
+    /// this tells nulls, empties and actual values of a string column apart
+    EmptyCount,
+    /// Number of non-null, non-empty strings
+    NonEmptyCount,
+    /// The first value in the order the input was sorted in (e.g. by
+    /// `DataFrame::sort` on plain columns). Without such an ordering, the value
+    /// of an arbitrary row.
+    First,
+    /// The last value, see `First`
+    Last,
+    /// Number of values smaller than the value before them (in the order of
+    /// `First`), i.e. breaking a monotonically increasing order. A null neither
+    /// violates the order nor is compared with the value after it.
+    MonotonicViolations,
+    /// Whether a numeric column holds both negative and positive values, null
+    /// if it holds no values at all
+    HasMixedSign,
+    /// `(max - min)` of an integer column relative to the range of its type,
+    /// e.g. tiny for an Int32 column that would fit an Int8
+    RangeUtilization,
+    /// The lexicographically smallest value of a binary column, hex-encoded
+    MinBytes,
+    /// The lexicographically largest value of a binary column, hex-encoded
+    MaxBytes,
+}
+
+/// How `median` treats an even number of values, see
+/// [`DataFrameDescriber::with_median_strategy`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MedianStrategy {
+    /// The mean of the two middle values, always as Float64
+    Interpolate,
+    /// The smaller of the two middle values
+    Lower,
+    /// The larger of the two middle values
+    Higher,
+}
+
+/// Statistics computed over a pair of columns by [`DataFrameDescriber::pairwise_stat`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PairwiseMethod {
+    /// Sample covariance
+    Covariance,
+    /// Pearson correlation
+    Correlation,
+}
+
+/// Rule flagging a value as an outlier, see
+/// [`DataFrameDescriber::detect_outlier_columns`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum OutlierMethod {
+    /// Outside `[q1 - k * iqr, q3 + k * iqr]` for `Iqr(k)`, commonly `k = 1.5`
+    Iqr(f64),
+    /// More than `z` standard deviations away from the mean for `ZScore(z)`
+    ZScore(f64),
+}
+
+/// Conventional reading of a population stability index, see
+/// [`DataFrameDescriber::population_stability_index`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PopulationShift {
+    /// PSI below 0.1
+    None,
+    /// PSI from 0.1 to 0.25
+    Moderate,
+    /// PSI above 0.25
+    Significant,
+}
+
+impl PopulationShift {
+    pub fn from_psi(psi: f64) -> Self {
+        if psi < 0.1 {
+            PopulationShift::None
+        } else if psi <= 0.25 {
+            PopulationShift::Moderate
+        } else {
+            PopulationShift::Significant
+        }
+    }
+}
+
+/// Returned by [`DataFrameDescriber::value_range_check`] when the min/max of a
+/// column cannot be computed.
+#[derive(Debug)]
+pub struct RangeCheckError {
+    pub column: String,
+    pub reason: String,
+}
+
+/// Returned by [`DataFrameDescriber::describe_with_schema_validation`] when the
+/// DataFrame doesn't match the expected schema.
+#[derive(Debug)]
+pub struct SchemaValidationError {
+    pub column: String,
+    pub reason: String,
+}
+
+/// Returned by [`DataFrameDescriber::assert_no_nulls`] with the `(column, null count)`
+/// of every column holding nulls.
+#[derive(Debug)]
+pub struct NullConstraintError {
+    pub columns: Vec<(String, u64)>,
+}
+
+/// A column flagged by [`DataFrameDescriber::sensitivity_report`] as potentially
+/// holding personal data.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SensitivityFlag {
+    pub column: String,
+    pub reason: String,
+}
+
+/// A column flagged by [`DataFrameDescriber::detect_data_type_mismatch`] as declared
+/// with a different type than its values suggest.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TypeMismatch {
+    pub column: String,
+    pub declared_type: DataType,
+    pub inferred_type: DataType,
+}
+
+/// Shares of the non-null values of a string column parsing as each type, see
+/// [`DataFrameDescriber::detect_mixed_type_string_columns`]. A value can parse as
+/// several types (e.g. `"1"` as a number and a boolean), so the numeric, date and
+/// boolean fractions may sum to more than 1.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MixedTypeReport {
+    pub numeric_fraction: f64,
+    pub date_fraction: f64,
+    pub boolean_fraction: f64,
+    /// Values parsing as none of the above
+    pub pure_string_fraction: f64,
+}
+
+/// Returned by [`DataFrameDescriber::monotonicity_check`]. Both flags are
+/// non-strict, so a constant column is monotone in both directions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MonotonicityResult {
+    pub is_monotone_increasing: bool,
+    pub is_monotone_decreasing: bool,
+    /// Values out of order for the direction the column follows more closely,
+    /// i.e. the smaller of the decreasing and increasing step counts
+    pub violations: u64,
+}
+
+/// When and how a `describe` result was computed, see
+/// [`DataFrameDescriber::describe_with_metadata`].
+#[derive(Debug, Clone)]
+pub struct DescribeMetadata {
+    pub computed_at: DateTime<Utc>,
+    pub original_row_count: u64,
+    pub original_column_count: usize,
+    pub methods: Vec<DescribeMethod>,
+    /// Wall-clock time spent computing the statistics.
+    pub elapsed: Duration,
+}
+
+/// Execution metrics of a describe run, see [`DataFrameDescriber::describe_with_metrics`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct DescribeMetrics {
+    /// Rows produced by the root of the physical plan.
+    pub output_rows: usize,
+    /// CPU time reported by all operators of the plan.
+    pub elapsed_compute: Duration,
+    /// Wall-clock time spent executing the plan.
+    pub elapsed: Duration,
 }
 
 #[derive(Debug)]
@@ -30,30 +373,30 @@ pub struct DataFrameDescriber {
     original: DataFrame,
     transformed: DataFrame,
     methods: Vec<DescribeMethod>,
+    percentile_udf: Option<Arc<AggregateUDF>>,
+    absolute_columns: Vec<String>,
+    output_batch_size: Option<usize>,
+    weight_col: Option<String>,
+    fingerprint_decimals: usize,
+    min_count: Option<usize>,
+    filter: Option<Expr>,
+    cast_fn: Option<CustomCast>,
+    fallback_transform: Option<CustomCast>,
+    pre_transforms: Vec<PreTransform>,
+    coerce_numeric_strings: bool,
+    exact_temporal: bool,
+    median_strategy: Option<MedianStrategy>,
+    output_names: HashMap<String, String>,
+    cache: Option<DescribeCache>,
+    version_metadata: bool,
+    dataset_summary: bool,
 }
 
 impl DataFrameDescriber {
     pub fn try_new(df: DataFrame) -> anyhow::Result<Self> {
-        let fields = df.schema().fields().iter();
-        // change all temporal columns to Float64
-        let expressions = fields
-            .map(|field| {
-                let dt = field.data_type();
-                let expr = match dt {
-                    dt if dt.is_temporal() => cast(col(field.name()), DataType::Float64),
-                    dt if dt.is_numeric() => col(field.name()),
-                    DataType::List(_) | DataType::LargeList(_) => array_length(col(field.name())),
-                    _ => length(cast(col(field.name()), DataType::Utf8)),
-                };
-                expr.alias(field.name())
-            })
-            .collect();
-
-        let transformed = df.clone().select(expressions)?;
-
-        Ok(Self {
-            original: df,
-            transformed,
+        let mut ret = Self {
+            original: df.clone(),
+            transformed: df,
             methods: vec![
                 DescribeMethod::Total,
                 DescribeMethod::NullTotal,
@@ -67,201 +410,8297 @@ impl DataFrameDescriber {
                 DescribeMethod::Percentile(50),
                 DescribeMethod::Percentile(75),
             ],
-        })
+            percentile_udf: None,
+            absolute_columns: vec![],
+            output_batch_size: None,
+            weight_col: None,
+            fingerprint_decimals: 6,
+            min_count: None,
+            filter: None,
+            cast_fn: None,
+            fallback_transform: None,
+            pre_transforms: vec![],
+            coerce_numeric_strings: false,
+            exact_temporal: false,
+            median_strategy: None,
+            output_names: HashMap::new(),
+            cache: None,
+            version_metadata: false,
+            dataset_summary: false,
+        };
+        ret.transformed = ret.transform()?;
+        Ok(ret)
     }
 
-    pub async fn describe(&self) -> anyhow::Result<DataFrame> {
-        let df = self.do_describe().await?;
-        self.cast_back(df)
-    }
-
-    async fn do_describe(&self) -> anyhow::Result<DataFrame> {
-        let df: Option<DataFrame> = self.methods.iter().fold(None, |acc, method| {
-            let df = self.transformed.clone();
-            let stat_df = match method {
-                DescribeMethod::Total => total(df).unwrap(),
-                DescribeMethod::NullTotal => null_total(df).unwrap(),
-                DescribeMethod::Mean => mean(df).unwrap(),
-                DescribeMethod::Stddev => std_div(df).unwrap(),
-                DescribeMethod::Min => minimum(df).unwrap(),
-                DescribeMethod::Max => maximum(df).unwrap(),
-                DescribeMethod::Median => med(df).unwrap(),
-                DescribeMethod::Percentile(p) => percentile(df, *p as f64 / 100.0).unwrap(),
-            };
-            // add a new column to the beginning of the DataFrame
-            let mut select_expr = vec![lit(method.to_string()).alias("describe")];
-            select_expr.extend(stat_df.schema().fields().iter().map(|f| col(f.name())));
+    /// Like `try_new`, but each column is converted by `cast_fn` instead of the
+    /// built-in coercions. `cast_fn` must return a numeric expression, e.g.
+    /// `length(cast(ident(field.name()), DataType::Utf8))`.
+    pub fn try_new_with_custom_cast(df: DataFrame, cast_fn: CastFn) -> anyhow::Result<Self> {
+        let mut ret = Self::try_new(df)?;
+        ret.cast_fn = Some(CustomCast(cast_fn));
+        ret.transformed = ret.transform()?;
+        Ok(ret)
+    }
+
+    /// Like `try_new`, but `describe` results are stored in and served from
+    /// `cache`, which can be shared by many describers. Entries are keyed by the
+    /// `describe` plan (so the methods and other options) and the tables it
+    /// reads, which entries keep alive; as plans are immutable, entries never go
+    /// stale.
+    pub fn try_new_with_cache(df: DataFrame, cache: DescribeCache) -> anyhow::Result<Self> {
+        let mut ret = Self::try_new(df)?;
+        ret.cache = Some(cache);
+        Ok(ret)
+    }
 
-            let stat_df = stat_df.select(select_expr).unwrap();
+    /// Describe the result of the SQL query `sql` run on `ctx`.
+    pub async fn try_from_sql(ctx: &SessionContext, sql: &str) -> anyhow::Result<Self> {
+        let df = ctx
+            .sql(sql)
+            .await
+            .with_context(|| format!("failed to run query: {}", sql))?;
+        Self::try_new(df)
+    }
 
-            match acc {
-                Some(acc) => Some(acc.union(stat_df).unwrap()),
-                None => Some(stat_df),
+    /// Describe the rows of all `frames` together, e.g. daily partitions. The
+    /// frames must have the same column names and types, in the same order.
+    pub fn try_new_concat(frames: Vec<DataFrame>) -> anyhow::Result<Self> {
+        let mut frames = frames.into_iter();
+        let Some(mut df) = frames.next() else {
+            anyhow::bail!("no DataFrames to concatenate");
+        };
+        for (i, frame) in frames.enumerate() {
+            let (expected, actual) = (df.schema().fields(), frame.schema().fields());
+            for j in 0..expected.len().max(actual.len()) {
+                match (expected.get(j), actual.get(j)) {
+                    (Some(e), Some(a)) if e.name() != a.name() => anyhow::bail!(
+                        "DataFrame {} has column {} where {} is expected",
+                        i + 1,
+                        a.name(),
+                        e.name()
+                    ),
+                    (Some(e), Some(a)) if e.data_type() != a.data_type() => anyhow::bail!(
+                        "column {} of DataFrame {} is {}, expected {}",
+                        a.name(),
+                        i + 1,
+                        a.data_type(),
+                        e.data_type()
+                    ),
+                    (Some(e), None) => {
+                        anyhow::bail!("DataFrame {} is missing column {}", i + 1, e.name())
+                    }
+                    (None, Some(a)) => {
+                        anyhow::bail!("DataFrame {} has extra column {}", i + 1, a.name())
+                    }
+                    _ => {}
+                }
             }
-        });
+            df = df.union(frame)?;
+        }
+        Self::try_new(df)
+    }
 
-        df.ok_or_else(|| anyhow::anyhow!("No statistics found"))
+    fn transform(&self) -> anyhow::Result<DataFrame> {
+        self.transform_frame(self.flattened()?)
     }
 
-    fn cast_back(&self, df: DataFrame) -> anyhow::Result<DataFrame> {
-        // we need the describe column
-        let describe = Arc::new(Field::new("describe", DataType::Utf8, false));
-        let mut fields = vec![&describe];
-        fields.extend(self.original.schema().fields().iter());
-        let expressions = fields
-            .into_iter()
-            .map(|field| {
-                let dt = field.data_type();
-                let expr = match dt {
-                    dt if dt.is_temporal() => cast(col(field.name()), dt.clone()),
-                    DataType::List(_) | DataType::LargeList(_) => {
-                        cast(col(field.name()), DataType::Int32)
-                    }
-                    _ => col(field.name()),
-                };
-                expr.alias(field.name())
-            })
+    fn transform_frame(&self, flattened: DataFrame) -> anyhow::Result<DataFrame> {
+        let expressions = flattened
+            .schema()
+            .fields()
+            .iter()
+            .map(|field| self.transform_field(field))
             .collect();
 
-        Ok(df
-            .select(expressions)?
-            .sort(vec![col("describe").sort(true, false)])?)
+        Ok(flattened.clone().select(expressions)?)
     }
-}
 
-impl fmt::Display for DescribeMethod {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
-            DescribeMethod::Total => write!(f, "total"),
-            DescribeMethod::NullTotal => write!(f, "null_total"),
-            DescribeMethod::Mean => write!(f, "mean"),
-            DescribeMethod::Stddev => write!(f, "stddev"),
-            DescribeMethod::Min => write!(f, "min"),
-            DescribeMethod::Max => write!(f, "max"),
-            DescribeMethod::Median => write!(f, "median"),
-            DescribeMethod::Percentile(p) => write!(f, "percentile_{}", p),
+    /// The numeric expression `field` is described by.
+    fn transform_field(&self, field: &Field) -> Expr {
+        let dt = field.data_type();
+        let is_weight = field.name() == WEIGHT_COLUMN;
+        if let Some(cast_fn) = self.cast_fn.as_ref().filter(|_| !is_weight) {
+            return (cast_fn.0)(field).alias(field.name());
         }
+        // change all temporal columns to Float64
+        let expr = match dt {
+            _ if is_weight => ident(field.name()),
+            dt if dt.is_temporal() => cast(ident(field.name()), self.temporal_stat_type()),
+            _ if self.absolute_columns.contains(field.name()) => abs(ident(field.name())),
+            dt if dt.is_numeric() => ident(field.name()),
+            DataType::List(_) | DataType::LargeList(_) => array_length(ident(field.name())),
+            DataType::Map(_, _) => map_length(dt).call(vec![ident(field.name())]),
+            // dictionary-encoded numbers are described by value, strings by length below
+            DataType::Dictionary(_, value_type) if value_type.is_numeric() => {
+                cast(ident(field.name()), value_type.as_ref().clone())
+            }
+            DataType::Utf8 | DataType::LargeUtf8 if self.coerce_numeric_strings => {
+                try_cast(ident(field.name()), DataType::Float64)
+            }
+            // binary data is described by its byte length, not as a string
+            DataType::Binary | DataType::LargeBinary => {
+                byte_length(dt).call(vec![ident(field.name())])
+            }
+            _ => match &self.fallback_transform {
+                Some(transform) => (transform.0)(field),
+                None => length(cast(ident(field.name()), DataType::Utf8)),
+            },
+        };
+        expr.alias(field.name())
     }
-}
 
-macro_rules! describe_method {
-    ($name:ident, $method:ident) => {
-        fn $name(df: DataFrame) -> anyhow::Result<DataFrame> {
-            let fields = df.schema().fields().iter();
-            let ret = df.clone().aggregate(
-                vec![],
-                fields
-                    .filter(|f| f.data_type().is_numeric())
-                    .map(|f| $method(col(f.name())).alias(f.name()))
-                    .collect::<Vec<_>>(),
-            )?;
-            Ok(ret)
+    /// The original DataFrame, rewritten by the `apply_before_describe` transforms.
+    fn pre_transformed(&self) -> anyhow::Result<DataFrame> {
+        let mut df = self.original.clone();
+        for transform in &self.pre_transforms {
+            df = (transform.0)(df)?;
         }
-    };
-}
+        Ok(df)
+    }
 
-describe_method!(total, count);
-describe_method!(mean, avg);
-describe_method!(std_div, stddev);
-describe_method!(minimum, min);
-describe_method!(maximum, max);
-describe_method!(med, median);
+    /// The original DataFrame, rewritten by the `apply_before_describe` transforms,
+    /// with struct columns expanded into one column per (nested) child field,
+    /// named `parent.child`.
+    /// The weight column of a weighted describer is not described itself, but
+    /// passed along as `WEIGHT_COLUMN`.
+    fn flattened(&self) -> anyhow::Result<DataFrame> {
+        let original = self.pre_transformed()?;
+        let mut expressions = vec![];
+        for field in original.schema().fields() {
+            if self.weight_col.as_ref() == Some(field.name()) {
+                continue;
+            }
+            flatten_field(
+                ident(field.name()),
+                field.name(),
+                field.data_type(),
+                &mut expressions,
+            );
+        }
+        if let Some(weight_col) = &self.weight_col {
+            expressions.push(cast(ident(weight_col), DataType::Float64).alias(WEIGHT_COLUMN));
+        }
+        Ok(original.select(expressions)?)
+    }
 
-fn null_total(df: DataFrame) -> anyhow::Result<DataFrame> {
-    let fields = df.schema().fields().iter();
-    let ret = df.clone().aggregate(
-        vec![],
-        fields
-            .map(|f| {
-                sum(case(is_null(col(f.name())))
-                    .when(lit(true), lit(1))
-                    .otherwise(lit(0))
-                    .unwrap())
-                .alias(f.name())
+    /// Like `try_new`, but `mean`, `stddev`, `median` and percentiles are weighted
+    /// by the numeric column `weight_col` (frequency weights), which is not described.
+    /// A custom percentile UDAF is not used for weighted percentiles.
+    pub fn try_new_weighted(df: DataFrame, weight_col: &str) -> anyhow::Result<Self> {
+        Self::try_new(df)?.weighted_by(weight_col)
+    }
+
+    /// Describe with statistics weighted by `weight_col`, see [`Self::try_new_weighted`].
+    pub async fn describe_with_weights(&self, weight_col: &str) -> anyhow::Result<DataFrame> {
+        self.derive(self.original.clone())?
+            .weighted_by(weight_col)?
+            .describe()
+            .await
+    }
+
+    fn weighted_by(mut self, weight_col: &str) -> anyhow::Result<Self> {
+        let field = self
+            .original
+            .schema()
+            .field_with_unqualified_name(weight_col)?;
+        if !field.data_type().is_numeric() {
+            anyhow::bail!("weight column {} is not numeric", weight_col);
+        }
+        self.weight_col = Some(weight_col.to_string());
+        self.transformed = self.transform()?;
+        Ok(self)
+    }
+
+    pub fn with_methods(mut self, methods: Vec<DescribeMethod>) -> Self {
+        self.methods = methods;
+        self
+    }
+
+    /// The describe configuration as JSON, `{"methods": ["total", ...]}` with the
+    /// method labels, to be stored with a pipeline and restored by [`Self::from_config`].
+    pub fn serialize_config(&self) -> String {
+        let methods = self
+            .methods
+            .iter()
+            .map(|m| m.to_string())
+            .collect::<Vec<_>>();
+        json!({ "methods": methods }).to_string()
+    }
+
+    /// Build a describer for `df` computing the methods of a configuration
+    /// produced by [`Self::serialize_config`].
+    pub fn from_config(config: &str, df: DataFrame) -> anyhow::Result<Self> {
+        let config: Value = serde_json::from_str(config).context("invalid describe config")?;
+        let methods = config
+            .get("methods")
+            .and_then(Value::as_array)
+            .ok_or_else(|| anyhow::anyhow!("describe config has no methods list"))?
+            .iter()
+            .map(|m| match m.as_str() {
+                Some(label) => label.parse(),
+                None => anyhow::bail!("describe config method {} is not a string", m),
             })
-            .collect::<Vec<_>>(),
-    )?;
-    Ok(ret)
-}
+            .collect::<anyhow::Result<Vec<DescribeMethod>>>()?;
+        Ok(Self::try_new(df)?.with_methods(methods))
+    }
 
-fn percentile(df: DataFrame, percentile: f64) -> anyhow::Result<DataFrame> {
-    let fields = df.schema().fields().iter();
-    let ret = df.clone().aggregate(
-        vec![],
-        fields
-            .filter(|f| f.data_type().is_numeric())
-            .map(|f| approx_percentile_cont(col(f.name()), lit(percentile)).alias(f.name()))
-            .collect::<Vec<_>>(),
-    )?;
-    Ok(ret)
-}
+    /// Also compute the `top_{p}` percentiles, e.g. `top_1` is the 99th percentile.
+    pub fn with_top_percentiles(mut self, percents: &[u8]) -> anyhow::Result<Self> {
+        for p in percents {
+            self.methods.push(DescribeMethod::top_percentile(*p)?);
+        }
+        Ok(self)
+    }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use datafusion::prelude::*;
-    use arrow::record_batch::RecordBatch;
-    use arrow::array::{Float64Array, Int32Array, StringArray, ArrayRef};
-    use arrow::datatypes::Schema;
-    use std::sync::Arc;
+    /// Use `udaf` instead of `approx_percentile_cont` for percentiles. It is
+    /// called as `udaf(column, fraction)`.
+    pub fn with_percentile_udf(mut self, udaf: Arc<AggregateUDF>) -> anyhow::Result<Self> {
+        data_types(&[DataType::Float64, DataType::Float64], udaf.signature()).map_err(|e| {
+            anyhow::anyhow!(
+                "percentile UDF {} must accept a numeric column and a fraction: {}",
+                udaf.name(),
+                e
+            )
+        })?;
+        self.percentile_udf = Some(udaf);
+        Ok(self)
+    }
 
+    /// Compute statistics on `abs(col)` for the given numeric columns.
+    pub fn with_absolute_columns(mut self, columns: &[&str]) -> anyhow::Result<Self> {
+        for name in columns {
+            let field = self.original.schema().field_with_unqualified_name(name)?;
+            if !field.data_type().is_numeric() {
+                anyhow::bail!("column {} is not numeric", name);
+            }
+        }
+        self.absolute_columns = columns.iter().map(|c| c.to_string()).collect();
+        self.transformed = self.transform()?;
+        Ok(self)
+    }
 
-    fn create_test_dataframe() -> DataFrame {
-        // Create a simple RecordBatch
-        let schema = Arc::new(Schema::new(vec![
-            Field::new("float_col", DataType::Float64, false),
-            Field::new("int_col", DataType::Int32, false),
-            Field::new("string_col", DataType::Utf8, false),
-        ]));
+    /// Transform columns of types without a built-in transform (strings,
+    /// booleans, ...) with `transform` instead of describing the length of
+    /// their string representation. `transform` must return a numeric expression.
+    pub fn with_fallback_transform(mut self, transform: CastFn) -> anyhow::Result<Self> {
+        self.fallback_transform = Some(CustomCast(transform));
+        self.transformed = self.transform()?;
+        Ok(self)
+    }
 
-        let batch = RecordBatch::try_new(
-            schema.clone(),
-            vec![
-                Arc::new(Float64Array::from(vec![1.0, 2.0, 3.0,4.0])) as ArrayRef,
-                Arc::new(Int32Array::from(vec![4, 5, 6, 7])) as ArrayRef,
-                Arc::new(StringArray::from(vec!["a", "b", "c", "d"])) as ArrayRef,
-            ],
-        ).unwrap();
+    /// Rewrite the DataFrame with `transform` (e.g. deduplicate or clip values)
+    /// before `describe` computes statistics, after any earlier transforms. Helpers
+    /// built on those statistics (e.g. `min_max_scale`) see the rewritten DataFrame;
+    /// the original is kept, and other analyses (e.g. `value_counts`) still see it.
+    /// Fails if `transform` does.
+    pub fn apply_before_describe(mut self, transform: PreTransformFn) -> anyhow::Result<Self> {
+        self.pre_transforms.push(PreTransform(transform));
+        self.transformed = self.transform()?;
+        Ok(self)
+    }
 
-        // Convert the RecordBatch to a DataFrame
-        let ctx = SessionContext::new();
-        ctx.read_batch(batch).unwrap()
+    /// Compute the statistics of temporal columns on their Int64 representation
+    /// rather than on Float64, which can't hold every nanosecond timestamp, so
+    /// e.g. `min`, `max` and `median` round-trip exactly. Means and standard
+    /// deviations are still computed in Float64, then truncated to whole units.
+    pub fn with_exact_temporal(mut self, exact: bool) -> anyhow::Result<Self> {
+        self.exact_temporal = exact;
+        self.transformed = self.transform()?;
+        Ok(self)
     }
 
-    #[tokio::test]
-    async fn test_try_new() {
-        let df = create_test_dataframe();
-        let describer = DataFrameDescriber::try_new(df);
-        assert!(describer.is_ok());
+    /// Type temporal columns are described as, see [`Self::with_exact_temporal`].
+    fn temporal_stat_type(&self) -> DataType {
+        if self.exact_temporal {
+            DataType::Int64
+        } else {
+            DataType::Float64
+        }
     }
 
-    #[tokio::test]
-    async fn test_describe_total() {
-        let df = create_test_dataframe();
-        let describer = DataFrameDescriber::try_new(df).unwrap();
+    /// Describe string columns by their values parsed as Float64 instead of by
+    /// their length, e.g. for numbers read from CSV as text. Unparseable values
+    /// are treated as nulls.
+    pub fn with_coerce_numeric_strings(mut self, coerce: bool) -> anyhow::Result<Self> {
+        self.coerce_numeric_strings = coerce;
+        self.transformed = self.transform()?;
+        Ok(self)
+    }
 
-        let described_df = describer.describe().await;
-        assert!(described_df.is_ok());
+    /// Normalize the output column names for downstream SQL or JSON consumers:
+    /// lowercased, with every non-alphanumeric character replaced by `_`. See
+    /// [`Self::name_mapping`] for the original names. Fails if two columns end up
+    /// with the same name.
+    pub fn with_sanitized_names(mut self, sanitize: bool) -> anyhow::Result<Self> {
+        self.output_names.clear();
+        if !sanitize {
+            return Ok(self);
+        }
+        let mut seen = HashMap::from([("describe".to_string(), "describe".to_string())]);
+        for f in self.unweighted_original()?.schema().fields() {
+            let name = f
+                .name()
+                .chars()
+                .map(|c| {
+                    if c.is_alphanumeric() {
+                        c.to_ascii_lowercase()
+                    } else {
+                        '_'
+                    }
+                })
+                .collect::<String>();
+            if let Some(other) = seen.insert(name.clone(), f.name().to_string()) {
+                anyhow::bail!(
+                    "columns {} and {} are both sanitized to {}",
+                    other,
+                    f.name(),
+                    name
+                );
+            }
+            if &name != f.name() {
+                self.output_names.insert(f.name().to_string(), name);
+            }
+        }
+        Ok(self)
+    }
 
-        let result = described_df.unwrap().collect().await.unwrap();
-        assert_eq!(result.len(), 1);  // should have one row for each statistic
-        assert_eq!(result[0].num_columns(), 4);  // describe + three columns
+    /// Output column names that differ from the original ones, mapped to the
+    /// original name, e.g. `col_a` to `Col A` with [`Self::with_sanitized_names`].
+    pub fn name_mapping(&self) -> HashMap<String, String> {
+        self.output_names
+            .iter()
+            .map(|(original, output)| (output.clone(), original.clone()))
+            .collect()
     }
-    #[tokio::test]
-    async fn test_percentile() {
-        let df = create_test_dataframe();
-        let percentile_df = percentile(df.clone(), 0.5).unwrap();
-        
-        let result = percentile_df.collect().await.unwrap();
-        
-        // Verify the percentile values
-        assert_eq!(result.len(), 1); // should have one row for the percentile
-        assert_eq!(result[0].num_columns(), 2); // percentile + one column
-        
-        let float_col = result[0].column(0).as_any().downcast_ref::<Float64Array>().unwrap();
-        assert_eq!(float_col.value(0), 2.5); // median of [1.0, 2.0, 3.0, 4.0] is 2.5
-        
-        let int_col = result[0].column(1).as_any().downcast_ref::<Int32Array>().unwrap();
-        assert_eq!(int_col.value(0), 5); // median of [4, 5, 6, 7] is 5.5 but approx_percentile_cont rounds down to 5
+
+    /// Fail if the DataFrame has more than `n` columns, a guardrail against
+    /// accidentally profiling very wide frames interactively.
+    pub fn with_max_columns(self, n: usize) -> anyhow::Result<Self> {
+        let columns = self.original.schema().fields().len();
+        if columns > n {
+            anyhow::bail!(
+                "DataFrame has {} columns, more than the limit of {}; describe a subset, e.g. \
+                 with `DataFrame::select_columns`",
+                columns,
+                n
+            );
+        }
+        Ok(self)
+    }
+
+    /// Coalesce the `describe` output into batches of at most `size` rows.
+    /// This only changes how the output is batched, not its values.
+    pub fn with_output_batch_size(mut self, size: usize) -> anyhow::Result<Self> {
+        if size == 0 {
+            anyhow::bail!("output batch size must be positive");
+        }
+        self.output_batch_size = Some(size);
+        Ok(self)
+    }
+
+    /// Only describe the rows matching `predicate`, e.g. `col("int_col").gt(lit(5))`.
+    /// The predicate sees the original values (struct fields as `parent.child`).
+    pub fn with_filter(mut self, predicate: Expr) -> Self {
+        self.filter = Some(predicate);
+        self
+    }
+
+    /// Compute the unweighted `median` with `strategy` rather than the built-in
+    /// `median` aggregate, which e.g. truncates the median of integer columns.
+    pub fn with_median_strategy(mut self, strategy: MedianStrategy) -> Self {
+        self.median_strategy = Some(strategy);
+        self
+    }
+
+    /// Version of the `describe` output layout produced by this crate.
+    pub const fn schema_version() -> &'static str {
+        SCHEMA_VERSION
+    }
+
+    /// Tag the schema of the `describe` output with [`Self::schema_version`] under
+    /// the [`SCHEMA_VERSION_KEY`] metadata key, for consumers of persisted outputs.
+    pub fn with_version_metadata(mut self, enabled: bool) -> Self {
+        self.version_metadata = enabled;
+        self
+    }
+
+    /// Append a `__dataset__` row summarizing the whole (filtered) dataset in the
+    /// extra columns `__dataset_rows`, `__dataset_columns`, `__dataset_cells` and
+    /// `__dataset_null_percentage` (of all cells). The described columns are null
+    /// in that row, and the extra columns in all others.
+    pub fn with_dataset_summary(mut self, enabled: bool) -> Self {
+        self.dataset_summary = enabled;
+        self
+    }
+
+    /// Null out the mean, stddev, median and percentiles of columns with fewer than
+    /// `n` non-null values, as they are unreliable. Counts are still reported.
+    pub fn with_min_count(mut self, n: usize) -> Self {
+        self.min_count = Some(n);
+        self
+    }
+
+    /// Number of decimals Float64 statistics are rounded to in
+    /// [`describe_fingerprint`](Self::describe_fingerprint), 6 by default.
+    pub fn with_fingerprint_decimals(mut self, decimals: usize) -> Self {
+        self.fingerprint_decimals = decimals;
+        self
+    }
+
+    /// A hash of the field names, types and nullability of the DataFrame, stable
+    /// across runs, to detect schema changes without describing the data.
+    /// It is not cryptographically secure.
+    pub fn schema_fingerprint(&self) -> u64 {
+        let mut hasher = Fnv1a::default();
+        for field in self.original.schema().fields() {
+            hasher.write(field.name().as_bytes());
+            hasher.write(format!("{:?}", field.data_type()).as_bytes());
+            hasher.write(&[field.is_nullable() as u8]);
+        }
+        hasher.0
+    }
+
+    /// A hash of the `describe` output (labels, column names and rounded values)
+    /// which is stable across runs and platforms, e.g. to snapshot a profile in CI.
+    pub async fn describe_fingerprint(&self) -> anyhow::Result<u64> {
+        let batches = self.describe().await?.collect().await?;
+
+        let mut hasher = Fnv1a::default();
+        for batch in &batches {
+            let schema = batch.schema();
+            for i in 0..batch.num_rows() {
+                for (field, array) in schema.fields().iter().zip(batch.columns()) {
+                    hasher.write(field.name().as_bytes());
+                    let value = match array.as_any().downcast_ref::<Float64Array>() {
+                        _ if array.is_null(i) => "null".to_string(),
+                        Some(values) => {
+                            format!("{:.*}", self.fingerprint_decimals, values.value(i))
+                        }
+                        None => array_value_to_string(array, i)?,
+                    };
+                    hasher.write(value.as_bytes());
+                }
+            }
+        }
+        Ok(hasher.0)
+    }
+
+    pub async fn describe(&self) -> anyhow::Result<DataFrame> {
+        let df = self.describe_logical()?;
+        let df = match &self.cache {
+            Some(cache) => cached(cache, df).await?,
+            None => df,
+        };
+        self.finish(df).await
+    }
+
+    /// Apply the output options (batch size, version metadata) to a `describe` result.
+    async fn finish(&self, df: DataFrame) -> anyhow::Result<DataFrame> {
+        let df = match self.output_batch_size {
+            Some(size) => rebatch(df, size).await?,
+            None => df,
+        };
+        if !self.version_metadata {
+            return Ok(df);
+        }
+        let metadata =
+            HashMap::from([(SCHEMA_VERSION_KEY.to_string(), SCHEMA_VERSION.to_string())]);
+        let schema = Arc::new(df.schema().as_arrow().clone().with_metadata(metadata));
+        let batches = df
+            .collect()
+            .await?
+            .into_iter()
+            .map(|batch| batch.with_schema(schema.clone()))
+            .collect::<Result<Vec<_>, _>>()?;
+        let table = MemTable::try_new(schema, vec![batches])?;
+        Ok(SessionContext::new().read_table(Arc::new(table))?)
+    }
+
+    /// Stacks the `describe` results of `self` and `other`, e.g. a training and a
+    /// test set, with a leading `source` column set to `"self"` or `"other"`.
+    /// Fails if the two results don't have the same columns and types.
+    pub async fn describe_union(&self, other: &DataFrameDescriber) -> anyhow::Result<DataFrame> {
+        let ours = self.describe().await?;
+        let theirs = other.describe().await?;
+        let (a, b) = (ours.schema().fields(), theirs.schema().fields());
+        if a.len() != b.len() {
+            anyhow::bail!(
+                "describe results are incompatible: {} vs {} columns",
+                a.len(),
+                b.len()
+            );
+        }
+        for (x, y) in a.iter().zip(b.iter()) {
+            if x.name() != y.name() || x.data_type() != y.data_type() {
+                anyhow::bail!(
+                    "describe results are incompatible: {} ({}) vs {} ({})",
+                    x.name(),
+                    x.data_type(),
+                    y.name(),
+                    y.data_type()
+                );
+            }
+        }
+
+        let label = |df: DataFrame, source: &str| {
+            let mut expressions = vec![lit(source).alias("source")];
+            expressions.extend(df.schema().fields().iter().map(|f| ident(f.name())));
+            df.select(expressions)
+        };
+        Ok(label(ours, "self")?.union(label(theirs, "other")?)?)
+    }
+
+    /// The raw statistics, skipping the final cast back and sort of `describe`:
+    /// every statistic stays Float64 (temporal columns are not cast back to their
+    /// type) and rows follow the union of the per-method results.
+    pub fn describe_raw(&self) -> anyhow::Result<DataFrame> {
+        self.do_describe()
+    }
+
+    /// Same as [`describe`](Self::describe), as a boxed future that can be raced
+    /// against a timeout (e.g. with `tokio::select!`). Nothing runs until it is
+    /// polled; dropping it cancels the work, and no partial result is exposed.
+    pub fn describe_lazy(&self) -> BoxFuture<'_, anyhow::Result<DataFrame>> {
+        Box::pin(self.describe())
+    }
+
+    /// Returns the `describe` output as an unevaluated DataFrame, e.g. to embed it
+    /// in a larger query. Unlike `describe`, it ignores `with_output_batch_size`,
+    /// which needs to execute the plan.
+    pub fn describe_logical(&self) -> anyhow::Result<DataFrame> {
+        self.cast_back(self.with_summary_row(self.do_describe()?)?)
+    }
+
+    /// Same as [`describe`](Self::describe), with the output columns named in
+    /// `renames` (original name to display name) renamed. Names without a match
+    /// are ignored; renaming to `describe` or to another output column fails.
+    pub async fn describe_with_column_renaming(
+        &self,
+        renames: HashMap<String, String>,
+    ) -> anyhow::Result<DataFrame> {
+        let df = self.unweighted_original()?;
+        let mut names = vec!["describe".to_string()];
+        names.extend(df.schema().fields().iter().map(|f| f.name().to_string()));
+        let mut output = names
+            .iter()
+            .map(|name| renames.get(name).unwrap_or(name))
+            .collect::<Vec<_>>();
+        output.sort();
+        if let Some(pair) = output.windows(2).find(|pair| pair[0] == pair[1]) {
+            anyhow::bail!("renaming yields duplicate column {}", pair[0]);
+        }
+        if renames.contains_key("describe") {
+            anyhow::bail!("the describe column can't be renamed");
+        }
+
+        let mut describer = self.derive(self.original.clone())?;
+        describer.output_names = renames;
+        describer.describe().await
+    }
+
+    /// The `describe` output in the wide layout, one row per column, see [`transpose`].
+    pub async fn describe_transposed(&self) -> anyhow::Result<DataFrame> {
+        transpose(self.describe().await?).await
+    }
+
+    /// The `describe` output in both the long and the wide layout (see
+    /// [`transpose`]). The statistics are computed once; both frames are built
+    /// from the same collected batches.
+    pub async fn describe_both(&self) -> anyhow::Result<(DataFrame, DataFrame)> {
+        let df = self.describe().await?;
+        let schema = Arc::new(df.schema().as_arrow().clone());
+        let batch = concat_batches(&schema, &df.collect().await?)?;
+        let long = SessionContext::new().read_batch(batch)?;
+        let wide = transpose(long.clone()).await?;
+        Ok((long, wide))
+    }
+
+    /// Streams the `describe` output in batches of `chunk_rows` rows (the last
+    /// one possibly shorter), for results too wide to handle at once.
+    ///
+    /// The output is currently computed in full before the first batch is
+    /// yielded; only the consumption is incremental.
+    pub async fn describe_chunked_output(
+        &self,
+        chunk_rows: usize,
+    ) -> anyhow::Result<impl Stream<Item = anyhow::Result<RecordBatch>>> {
+        if chunk_rows == 0 {
+            anyhow::bail!("chunk_rows must be positive");
+        }
+        let df = self.describe().await?;
+        let schema = Arc::new(df.schema().as_arrow().clone());
+        let batch = concat_batches(&schema, &df.collect().await?)?;
+        let chunks = slice_rows(&batch, chunk_rows).map(Ok).collect::<Vec<_>>();
+        Ok(stream::iter(chunks))
+    }
+
+    /// Describe each partition, i.e. each distinct combination of
+    /// `partition_cols`, separately. The partition columns lead the output and
+    /// every partition contributes one row per method.
+    ///
+    /// Each partition is read through a filter on the partition columns, so a
+    /// partitioned table (e.g. a hive-style Parquet directory) only scans the
+    /// files of the partition being described.
+    pub async fn describe_by_partition(
+        &self,
+        partition_cols: &[&str],
+    ) -> anyhow::Result<DataFrame> {
+        if partition_cols.is_empty() {
+            anyhow::bail!("at least one partition column is required");
+        }
+        for name in partition_cols {
+            self.original.schema().field_with_unqualified_name(name)?;
+        }
+        let partitions = self
+            .original
+            .clone()
+            .select(partition_cols.iter().map(|c| ident(*c)).collect())?
+            .distinct()?
+            .sort(
+                partition_cols
+                    .iter()
+                    .map(|c| ident(*c).sort(true, false))
+                    .collect(),
+            )?
+            .collect()
+            .await?;
+        let values = self
+            .original
+            .schema()
+            .fields()
+            .iter()
+            .filter(|f| !partition_cols.contains(&f.name().as_str()))
+            .map(|f| ident(f.name()))
+            .collect::<Vec<_>>();
+
+        let mut ret = vec![];
+        for batch in partitions {
+            for i in 0..batch.num_rows() {
+                let mut predicate = lit(true);
+                let mut select_expr = vec![];
+                for (name, array) in partition_cols.iter().zip(batch.columns()) {
+                    let value = ScalarValue::try_from_array(array, i)?;
+                    predicate = predicate.and(if value.is_null() {
+                        ident(*name).is_null()
+                    } else {
+                        ident(*name).eq(lit(value.clone()))
+                    });
+                    select_expr.push(lit(value).alias(*name));
+                }
+                let df = self
+                    .original
+                    .clone()
+                    .filter(predicate)?
+                    .select(values.clone())?;
+                let described = self.derive(df)?.describe_logical()?;
+                select_expr.extend(described.schema().fields().iter().map(|f| ident(f.name())));
+                ret.extend(described.select(select_expr)?.collect().await?);
+            }
+        }
+        Ok(SessionContext::new().read_batches(ret)?)
+    }
+
+    /// Describe `agg_cols` per distinct combination of `group_cols` with a single
+    /// grouped aggregation, in tidy format: one `(group_key, column_name,
+    /// stat_name, value)` row per group, column and method. `group_key` joins the
+    /// group values with `", "`. Only methods expressible as a plain aggregate are
+    /// supported (counts, mean, stddev, min, max, median and percentiles), and
+    /// weights are ignored.
+    pub async fn group_by_then_describe(
+        &self,
+        group_cols: &[&str],
+        agg_cols: &[&str],
+    ) -> anyhow::Result<DataFrame> {
+        if group_cols.is_empty() || agg_cols.is_empty() {
+            anyhow::bail!("at least one group and one aggregate column are required");
+        }
+        let mut flattened = self.flattened()?;
+        if let Some(predicate) = &self.filter {
+            flattened = flattened.filter(predicate.clone())?;
+        }
+        let group_key = concat_ws(
+            lit(", "),
+            group_cols
+                .iter()
+                .map(|c| coalesce(vec![cast(ident(*c), DataType::Utf8), lit("null")]))
+                .collect(),
+        );
+        let mut expressions = vec![group_key.alias("group_key")];
+        for name in agg_cols {
+            let field = flattened.schema().field_with_unqualified_name(name)?;
+            expressions.push(self.transform_field(field));
+        }
+        let input = flattened.clone().select(expressions)?;
+
+        let mut stats = vec![];
+        let mut aggregates = vec![];
+        for name in agg_cols {
+            let field = input.schema().field_with_unqualified_name(name)?;
+            for method in &self.methods {
+                let Some(expr) = typed_stat(method, field, self.percentile_udf.as_deref()) else {
+                    anyhow::bail!("{} is not supported in a grouped describe", method);
+                };
+                aggregates.push(expr.alias(format!("__stat_{}", stats.len())));
+                stats.push((*name, method.to_string()));
+            }
+        }
+        let grouped = input
+            .aggregate(vec![col("group_key")], aggregates)?
+            .sort(vec![col("group_key").sort(true, false)])?
+            .collect()
+            .await?;
+
+        let (mut keys, mut columns, mut labels, mut values) = (vec![], vec![], vec![], vec![]);
+        for batch in &grouped {
+            let key_array = batch.column_by_name("group_key").expect("group key");
+            let stat_arrays = (0..stats.len())
+                .map(|i| {
+                    let array = batch
+                        .column_by_name(&format!("__stat_{}", i))
+                        .expect("stat");
+                    cast_array(array, &DataType::Float64)
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            for row in 0..batch.num_rows() {
+                let key = stringify(key_array, row)?;
+                for ((name, label), array) in stats.iter().zip(&stat_arrays) {
+                    let array = array.as_any().downcast_ref::<Float64Array>().unwrap();
+                    keys.push(key.clone());
+                    columns.push(*name);
+                    labels.push(label.clone());
+                    values.push(array.is_valid(row).then(|| array.value(row)));
+                }
+            }
+        }
+
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("group_key", DataType::Utf8, false),
+            Field::new("column_name", DataType::Utf8, false),
+            Field::new("stat_name", DataType::Utf8, false),
+            Field::new("value", DataType::Float64, true),
+        ]));
+        let batch = RecordBatch::try_new(
+            schema,
+            vec![
+                Arc::new(StringArray::from(keys)),
+                Arc::new(StringArray::from(columns)),
+                Arc::new(StringArray::from(labels)),
+                Arc::new(Float64Array::from(values)),
+            ],
+        )?;
+        Ok(SessionContext::new().read_batch(batch)?)
+    }
+
+    /// Returns the `describe` output followed by the first `n` rows of the data
+    /// (fewer if there aren't as many), labeled `sample_0`, `sample_1`, ...
+    /// All columns are stringified so statistics and sample values can share them.
+    /// The `with_dataset_summary` columns are null in the sample rows.
+    pub async fn describe_sample_rows(&self, n: usize) -> anyhow::Result<DataFrame> {
+        let stats = self.describe().await?.collect().await?;
+        let samples = self
+            .unweighted_original()?
+            .limit(0, Some(n))?
+            .collect()
+            .await?;
+
+        let stats_schema = self.describe_as_arrow_schema()?;
+        let names = stats_schema
+            .fields()
+            .iter()
+            .map(|f| f.name().as_str())
+            .collect::<Vec<_>>();
+        let mut columns = vec![vec![]; names.len()];
+        for batch in &stats {
+            for (values, name) in columns.iter_mut().zip(&names) {
+                let array = batch
+                    .column_by_name(name)
+                    .with_context(|| format!("no describe output column {}", name))?;
+                for i in 0..batch.num_rows() {
+                    values.push(stringify(array, i)?);
+                }
+            }
+        }
+        // the sample values of each output column, by its original name
+        let mapping = self.name_mapping();
+        let mut row = 0;
+        for batch in &samples {
+            for i in 0..batch.num_rows() {
+                columns[0].push(Some(format!("sample_{}", row)));
+                row += 1;
+                for (values, name) in columns.iter_mut().zip(&names).skip(1) {
+                    if DATASET_SUMMARY_COLUMNS.contains(name) {
+                        values.push(None);
+                        continue;
+                    }
+                    let source = mapping.get(*name).map_or(*name, |s| s.as_str());
+                    let array = batch
+                        .column_by_name(source)
+                        .with_context(|| format!("no column {} to sample", source))?;
+                    values.push(stringify(array, i)?);
+                }
+            }
+        }
+
+        let schema = Arc::new(Schema::new(
+            names
+                .iter()
+                .map(|name| Field::new(*name, DataType::Utf8, *name != "describe"))
+                .collect::<Vec<_>>(),
+        ));
+        let batch = RecordBatch::try_new(
+            schema,
+            columns
+                .into_iter()
+                .map(|values| Arc::new(StringArray::from(values)) as ArrayRef)
+                .collect(),
+        )?;
+        Ok(SessionContext::new().read_batch(batch)?)
+    }
+
+    /// Returns a single row with one struct column per (non-nested) input column,
+    /// holding the statistics with their native types: counts as Int64, `min`,
+    /// `max`, `median` and percentiles as the column type (percentiles only for
+    /// numeric columns), `mean` and `stddev` as Float64. Methods without a typed
+    /// form (e.g. `is_constant`) are left out.
+    pub async fn describe_struct(&self) -> anyhow::Result<DataFrame> {
+        let df = self.unweighted_original()?;
+        let fields = df
+            .schema()
+            .fields()
+            .iter()
+            .filter(|f| !f.data_type().is_nested())
+            .cloned()
+            .collect::<Vec<_>>();
+        let key = |f: &Field, method: &DescribeMethod| format!("{}__{}", f.name(), method);
+
+        let mut aggr_expr = vec![];
+        let mut struct_expr = vec![];
+        for f in &fields {
+            let mut args = vec![];
+            for method in &self.methods {
+                let Some(expr) = typed_stat(method, f, self.percentile_udf.as_deref()) else {
+                    continue;
+                };
+                aggr_expr.push(expr.alias(key(f, method)));
+                args.extend([lit(method.to_string()), ident(key(f, method))]);
+            }
+            if !args.is_empty() {
+                struct_expr.push(named_struct(args).alias(f.name()));
+            }
+        }
+        if struct_expr.is_empty() {
+            anyhow::bail!("no statistics to describe");
+        }
+        Ok(df.aggregate(vec![], aggr_expr)?.select(struct_expr)?)
+    }
+
+    /// Returns the Arrow schema of the `describe` output without executing it:
+    /// Float64 statistics, the original type for temporal columns, Int32 for
+    /// list/map lengths and Utf8 for stringified statistics.
+    pub fn describe_as_arrow_schema(&self) -> anyhow::Result<Schema> {
+        let df = self.describe_logical()?;
+        Ok(df.schema().as_arrow().clone())
+    }
+
+    /// Describe the numeric columns the way pandas' `DataFrame.describe()` does:
+    /// rows `count, mean, std, min, 25%, 50%, 75%, max`, in that order.
+    pub async fn describe_pandas_compatible(&self) -> anyhow::Result<DataFrame> {
+        let numeric = self
+            .original
+            .schema()
+            .fields()
+            .iter()
+            .filter(|f| f.data_type().is_numeric())
+            .map(|f| ident(f.name()))
+            .collect::<Vec<_>>();
+        if numeric.is_empty() {
+            anyhow::bail!("no numeric columns to describe");
+        }
+        let methods = PANDAS_METHODS.iter().map(|(m, _)| m.clone()).collect();
+        let df = self
+            .derive(self.original.clone().select(numeric)?)?
+            .with_methods(methods)
+            .describe()
+            .await?;
+
+        let mut label = case(col("describe"));
+        let mut order = case(col("describe"));
+        for (i, (method, name)) in PANDAS_METHODS.iter().enumerate() {
+            label.when(lit(method.to_string()), lit(*name));
+            order.when(lit(method.to_string()), lit(i as i32));
+        }
+        let mut select_expr = vec![label.end()?.alias("describe")];
+        select_expr.extend(
+            df.schema()
+                .fields()
+                .iter()
+                .filter(|f| f.name() != "describe")
+                .map(|f| ident(f.name())),
+        );
+        Ok(df
+            .sort(vec![order.end()?.sort(true, false)])?
+            .select(select_expr)?)
+    }
+
+    /// Checks the DataFrame has exactly the columns of `expected_schema` with
+    /// compatible types (equal, both numeric or both strings) before describing it.
+    pub async fn describe_with_schema_validation(
+        &self,
+        expected_schema: &Schema,
+    ) -> anyhow::Result<DataFrame> {
+        let error = |column: &str, reason: String| SchemaValidationError {
+            column: column.to_string(),
+            reason,
+        };
+        let actual = self.original.schema();
+        for expected in expected_schema.fields() {
+            let Ok(field) = actual.field_with_unqualified_name(expected.name()) else {
+                return Err(error(expected.name(), "missing".to_string()).into());
+            };
+            if !compatible_types(field.data_type(), expected.data_type()) {
+                let reason = format!(
+                    "expected {}, found {}",
+                    expected.data_type(),
+                    field.data_type()
+                );
+                return Err(error(expected.name(), reason).into());
+            }
+        }
+        for field in actual.fields() {
+            if expected_schema.field_with_name(field.name()).is_err() {
+                return Err(error(field.name(), "unexpected".to_string()).into());
+            }
+        }
+        self.describe().await
+    }
+
+    /// Same as [`describe`](Self::describe), but each method is computed (executed)
+    /// on its own, with `on_method_start` called before and `on_method_end`
+    /// (given the elapsed time) after it, e.g. to export per-statistic timings.
+    pub async fn describe_with_hooks<F, G>(
+        &self,
+        on_method_start: F,
+        on_method_end: G,
+    ) -> anyhow::Result<DataFrame>
+    where
+        F: Fn(&DescribeMethod),
+        G: Fn(&DescribeMethod, Duration),
+    {
+        let ctx = SessionContext::new();
+        let mut ret: Option<DataFrame> = None;
+        for method in &self.methods {
+            on_method_start(method);
+            let start = Instant::now();
+            let df = self.scoped(vec![method.clone()])?.describe_raw()?;
+            let schema = Arc::new(df.schema().as_arrow().clone());
+            let batch = concat_batches(&schema, &df.collect().await?)?;
+            on_method_end(method, start.elapsed());
+
+            let df = ctx.read_batch(batch)?;
+            ret = Some(match ret {
+                Some(acc) => acc.union(df)?,
+                None => df,
+            });
+        }
+        let df = ret.ok_or_else(|| anyhow::anyhow!("No statistics found"))?;
+        let df = self.cast_back(self.with_summary_row(df)?)?;
+        self.finish(df).await
+    }
+
+    /// Returns the physical plan of `describe` as a Graphviz DOT digraph, one node
+    /// per operator (e.g. each per-method aggregate feeding the union), with
+    /// edges from inputs to the operators consuming them. Nothing is executed.
+    pub async fn describe_plan_dot(&self) -> anyhow::Result<String> {
+        let plan = self.describe_logical()?.create_physical_plan().await?;
+        let mut dot = String::from("digraph describe {\n    node [shape=box];\n");
+        plan_dot(plan.as_ref(), &mut 0, &mut dot);
+        dot.push_str("}\n");
+        Ok(dot)
+    }
+
+    /// Same as [`describe`](Self::describe), but executes the plan eagerly and
+    /// also returns the DataFusion metrics of that execution.
+    pub async fn describe_with_metrics(&self) -> anyhow::Result<(DataFrame, DescribeMetrics)> {
+        let df = self.describe_logical()?;
+        let task_ctx = Arc::new(df.task_ctx());
+        let plan = df.create_physical_plan().await?;
+
+        let start = Instant::now();
+        let batches = collect(plan.clone(), task_ctx).await?;
+        let elapsed = start.elapsed();
+
+        let metrics = DescribeMetrics {
+            output_rows: plan
+                .metrics()
+                .and_then(|m| m.output_rows())
+                .unwrap_or_default(),
+            elapsed_compute: Duration::from_nanos(elapsed_compute(plan.as_ref()) as u64),
+            elapsed,
+        };
+        let df = self
+            .finish(SessionContext::new().read_batches(batches)?)
+            .await?;
+        Ok((df, metrics))
+    }
+
+    /// Same as [`describe`](Self::describe), but executes eagerly and also returns
+    /// when and how the statistics were computed, e.g. for audit logs.
+    pub async fn describe_with_metadata(&self) -> anyhow::Result<(DataFrame, DescribeMetadata)> {
+        let computed_at = Utc::now();
+        let start = Instant::now();
+        let df = self.describe().await?;
+        let schema = Arc::new(df.schema().as_arrow().clone());
+        let batch = concat_batches(&schema, &df.collect().await?)?;
+        let elapsed = start.elapsed();
+
+        let metadata = DescribeMetadata {
+            computed_at,
+            original_row_count: self.original.clone().count().await? as u64,
+            original_column_count: self.original.schema().fields().len(),
+            methods: self.methods.clone(),
+            elapsed,
+        };
+        Ok((SessionContext::new().read_batch(batch)?, metadata))
+    }
+
+    /// Describe the Parquet data at `url` (`s3://`, `gs://` or `az://`).
+    ///
+    /// Unless `ctx` already has an object store for the URL's scheme and bucket
+    /// (see `RuntimeEnv::register_object_store`), one is registered, configured
+    /// by the scheme's object store builder `from_env`, e.g. `AWS_ACCESS_KEY_ID`
+    /// or `AZURE_STORAGE_ACCOUNT_NAME`.
+    pub async fn describe_remote(url: &str, ctx: &SessionContext) -> anyhow::Result<DataFrame> {
+        register_object_store(ctx, url)?;
+        let df = ctx.read_parquet(url, Default::default()).await?;
+        Self::try_new(df)?.describe().await
+    }
+
+    /// Returns `(total_rows, duplicate_rows)` of the original DataFrame, where
+    /// `duplicate_rows` is the number of distinct rows occurring more than once.
+    pub async fn detect_duplicate_rows(&self) -> anyhow::Result<(u64, u64)> {
+        let total = self.original.clone().count().await? as u64;
+
+        let group_expr = self
+            .original
+            .schema()
+            .fields()
+            .iter()
+            .map(|f| ident(f.name()))
+            .collect::<Vec<_>>();
+        let duplicates = self
+            .original
+            .clone()
+            .aggregate(group_expr, vec![count(lit(1)).alias("n")])?
+            .filter(col("n").gt(lit(1)))?
+            .count()
+            .await? as u64;
+
+        Ok((total, duplicates))
+    }
+
+    /// Returns the statistics keyed by column name, then by statistic label.
+    /// Stringified statistics (e.g. `is_constant`) are omitted.
+    ///
+    /// Aggregation happens inside the plan, so only the summary rows (one per
+    /// method) are materialized, regardless of the input size.
+    pub async fn describe_map(
+        &self,
+    ) -> anyhow::Result<HashMap<String, HashMap<String, Option<f64>>>> {
+        let batches = self.do_describe()?.collect().await?;
+
+        let mut ret: HashMap<String, HashMap<String, Option<f64>>> = HashMap::new();
+        for batch in batches {
+            let labels = batch
+                .column_by_name("describe")
+                .and_then(|c| c.as_any().downcast_ref::<StringArray>())
+                .ok_or_else(|| anyhow::anyhow!("describe column not found"))?;
+            let schema = batch.schema();
+            for field in schema.fields() {
+                let Some(column) = batch.column_by_name(field.name()) else {
+                    continue;
+                };
+                if column.data_type() == &DataType::Utf8 {
+                    continue;
+                }
+                let values = cast_array(column, &DataType::Float64)?;
+                let values = values
+                    .as_any()
+                    .downcast_ref::<Float64Array>()
+                    .expect("cast to Float64 must yield a Float64Array");
+                let stats = ret.entry(field.name().to_string()).or_default();
+                for (label, value) in labels.iter().zip(values.iter()) {
+                    if let Some(label) = label {
+                        stats.insert(label.to_string(), value);
+                    }
+                }
+            }
+        }
+
+        Ok(ret)
+    }
+
+    /// Writes the describe result to `writer` in the Arrow IPC streaming format.
+    pub async fn describe_to_ipc_stream<W: Write + Send>(&self, writer: W) -> anyhow::Result<()> {
+        let df = self.describe().await?;
+        let schema = df.schema().as_arrow().clone();
+        let batches = df.collect().await?;
+
+        let mut writer = StreamWriter::try_new(writer, &schema)?;
+        for batch in &batches {
+            writer.write(batch)?;
+        }
+        writer.finish()?;
+        Ok(())
+    }
+
+    /// Writes the describe result as a Delta table at `path`. `Overwrite` replaces the
+    /// table contents, `Append` adds a new version alongside earlier ones, creating the
+    /// table on first use.
+    #[cfg(feature = "deltalake")]
+    pub async fn describe_to_delta_lake(&self, path: &str, mode: SaveMode) -> anyhow::Result<()> {
+        let create_mode = match mode {
+            SaveMode::Overwrite => SaveMode::Overwrite,
+            SaveMode::Append => SaveMode::Ignore,
+            other => anyhow::bail!("unsupported save mode for describe_to_delta_lake: {other:?}"),
+        };
+
+        let df = self.describe().await?;
+        let schema = df.schema().as_arrow().clone();
+        let batches = df.collect().await?;
+
+        let columns = StructType::try_from(&schema)?;
+        let mut table = DeltaOps::try_from_uri(path)
+            .await?
+            .create()
+            .with_columns(columns.fields().iter().cloned())
+            // Writer version 1: the describe output carries no invariants or constraints.
+            .with_actions([Action::Protocol(Protocol::new(1, 1))])
+            .with_save_mode(create_mode)
+            .await?;
+
+        let mut writer = RecordBatchWriter::for_table(&table)?;
+        for batch in batches {
+            let batch = RecordBatch::try_new(writer.arrow_schema(), batch.columns().to_vec())?;
+            writer.write(batch).await?;
+        }
+        writer.flush_and_commit(&mut table).await?;
+        Ok(())
+    }
+
+    /// Describes overlapping windows of `window` rows, starting every `step` rows.
+    /// Windows follow the row order of the original DataFrame.
+    pub async fn rolling_describe(
+        &self,
+        window: usize,
+        step: usize,
+    ) -> anyhow::Result<Vec<DataFrame>> {
+        if window == 0 || step == 0 {
+            anyhow::bail!("window and step must be greater than 0");
+        }
+        let row_count = self.original.clone().count().await?;
+        if window > row_count {
+            anyhow::bail!(
+                "window size {} is larger than the row count {}",
+                window,
+                row_count
+            );
+        }
+
+        let mut ret = vec![];
+        for start in (0..=row_count - window).step_by(step) {
+            let df = self.original.clone().limit(start, Some(window))?;
+            ret.push(self.derive(df)?.describe().await?);
+        }
+        Ok(ret)
+    }
+
+    /// Ranks numeric columns by their coefficient of variation (stddev / mean),
+    /// returning `(column_name, mean, stddev, cv)` rows in descending cv order.
+    pub async fn feature_importance_proxy(&self) -> anyhow::Result<DataFrame> {
+        let stats = self
+            .scoped(vec![DescribeMethod::Mean, DescribeMethod::Stddev])?
+            .describe_map()
+            .await?;
+
+        let mut rows = vec![];
+        for field in self.pre_transformed()?.schema().fields() {
+            // the weight column is numeric but not described
+            let Some(stat) = stats
+                .get(field.name())
+                .filter(|_| field.data_type().is_numeric())
+            else {
+                continue;
+            };
+            let (Some(mean), Some(stddev)) = (stat["mean"], stat["stddev"]) else {
+                anyhow::bail!("mean or stddev of column {} is null", field.name());
+            };
+            if mean == 0.0 {
+                anyhow::bail!(
+                    "coefficient of variation is undefined for column {}: mean is zero",
+                    field.name()
+                );
+            }
+            rows.push((field.name().to_string(), mean, stddev, stddev / mean));
+        }
+        rows.sort_by(|a, b| b.3.total_cmp(&a.3));
+
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("column_name", DataType::Utf8, false),
+            Field::new("mean", DataType::Float64, false),
+            Field::new("stddev", DataType::Float64, false),
+            Field::new("cv", DataType::Float64, false),
+        ]));
+        let batch = RecordBatch::try_new(
+            schema,
+            vec![
+                Arc::new(StringArray::from_iter_values(rows.iter().map(|r| &r.0))) as ArrayRef,
+                Arc::new(Float64Array::from_iter_values(rows.iter().map(|r| r.1))),
+                Arc::new(Float64Array::from_iter_values(rows.iter().map(|r| r.2))),
+                Arc::new(Float64Array::from_iter_values(rows.iter().map(|r| r.3))),
+            ],
+        )?;
+        Ok(SessionContext::new().read_batch(batch)?)
+    }
+
+    /// Returns a column-centric report for data contracts:
+    /// `{"columns": {<column>: {"type", "completeness", "distinct_count", "min", "max"}}}`.
+    /// `min` and `max` keep the column's own values (numbers as JSON numbers, other
+    /// types as strings) and are null for nested columns, as is `distinct_count`.
+    pub async fn describe_contract(&self) -> anyhow::Result<Value> {
+        let completeness = self.completeness().await?;
+        let df = self.unweighted_original()?;
+        let fields = df.schema().fields().clone();
+        let key = |name: &str, stat: &str| format!("{}__{}", name, stat);
+
+        let mut aggr_expr = vec![];
+        for f in fields.iter().filter(|f| !f.data_type().is_nested()) {
+            let x = ident(f.name());
+            aggr_expr.push(count_distinct(x.clone()).alias(key(f.name(), "distinct_count")));
+            aggr_expr.push(min(x.clone()).alias(key(f.name(), "min")));
+            aggr_expr.push(max(x).alias(key(f.name(), "max")));
+        }
+        let batches = if aggr_expr.is_empty() {
+            vec![]
+        } else {
+            df.aggregate(vec![], aggr_expr)?.collect().await?
+        };
+        let row = batches.iter().find(|b| b.num_rows() > 0);
+        let stat = |name: &str, s: &str| -> anyhow::Result<Value> {
+            let Some(array) = row.and_then(|b| b.column_by_name(&key(name, s))) else {
+                return Ok(Value::Null);
+            };
+            json_value(array, 0)
+        };
+
+        let mut columns = Map::new();
+        for f in fields.iter() {
+            columns.insert(
+                f.name().to_string(),
+                json!({
+                    "type": f.data_type().to_string(),
+                    "completeness": completeness.get(f.name()),
+                    "distinct_count": stat(f.name(), "distinct_count")?,
+                    "min": stat(f.name(), "min")?,
+                    "max": stat(f.name(), "max")?,
+                }),
+            );
+        }
+        Ok(json!({ "columns": columns }))
+    }
+
+    /// Returns a Great Expectations expectation suite (as JSON) derived from the
+    /// statistics: `expect_column_values_to_not_be_null` for columns with less than
+    /// 5% nulls (with `mostly` set to the completeness if there are any), and
+    /// `expect_column_mean_to_be_between` within two standard deviations of the
+    /// mean for numeric columns.
+    pub async fn export_to_great_expectations_json(&self) -> anyhow::Result<String> {
+        let stats = self
+            .scoped(vec![
+                DescribeMethod::Total,
+                DescribeMethod::NullTotal,
+                DescribeMethod::Mean,
+                DescribeMethod::Stddev,
+            ])?
+            .describe_map()
+            .await?;
+
+        let mut expectations = vec![];
+        for f in self.unweighted_original()?.schema().fields() {
+            let stat = described(&stats, f.name())?;
+            let present = stat["total"].unwrap_or_default();
+            let rows = present + stat["null_total"].unwrap_or_default();
+            if rows > 0.0 && 1.0 - present / rows < LOW_NULL_RATIO {
+                let mut kwargs = json!({ "column": f.name() });
+                if present < rows {
+                    kwargs["mostly"] = json!(present / rows);
+                }
+                expectations.push(json!({
+                    "expectation_type": "expect_column_values_to_not_be_null",
+                    "kwargs": kwargs,
+                }));
+            }
+            if !f.data_type().is_numeric() {
+                continue;
+            }
+            if let (Some(mean), Some(stddev)) = (stat["mean"], stat["stddev"]) {
+                expectations.push(json!({
+                    "expectation_type": "expect_column_mean_to_be_between",
+                    "kwargs": {
+                        "column": f.name(),
+                        "min_value": mean - 2.0 * stddev,
+                        "max_value": mean + 2.0 * stddev,
+                    },
+                }));
+            }
+        }
+        let suite = json!({
+            "expectation_suite_name": "describe",
+            "expectations": expectations,
+            "meta": { "generated_by": "DataFrameDescriber" },
+        });
+        Ok(serde_json::to_string_pretty(&suite)?)
+    }
+
+    /// Fails with a [`NullConstraintError`] if any of `cols` (all columns if empty)
+    /// holds nulls.
+    pub async fn assert_no_nulls(&self, cols: &[&str]) -> anyhow::Result<()> {
+        let df = self.unweighted_original()?;
+        for name in cols {
+            df.schema().field_with_unqualified_name(name)?;
+        }
+        let names = if cols.is_empty() {
+            df.schema()
+                .fields()
+                .iter()
+                .map(|f| f.name().to_string())
+                .collect::<Vec<_>>()
+        } else {
+            cols.iter().map(|c| c.to_string()).collect()
+        };
+        let stats = self
+            .scoped(vec![DescribeMethod::NullTotal])?
+            .describe_map()
+            .await?;
+
+        let mut columns = vec![];
+        for name in names {
+            let nulls = described(&stats, &name)?["null_total"].unwrap_or_default() as u64;
+            if nulls > 0 {
+                columns.push((name, nulls));
+            }
+        }
+        if columns.is_empty() {
+            Ok(())
+        } else {
+            Err(NullConstraintError { columns }.into())
+        }
+    }
+
+    /// Returns the completeness (`1 - null_fraction`) of every column, in `[0, 1]`.
+    /// Columns of an empty DataFrame have a completeness of 0.
+    pub async fn completeness(&self) -> anyhow::Result<HashMap<String, f64>> {
+        let stats = self
+            .scoped(vec![DescribeMethod::Total, DescribeMethod::NullTotal])?
+            .describe_map()
+            .await?;
+
+        Ok(stats
+            .into_iter()
+            .map(|(name, stat)| {
+                let present = stat["total"].unwrap_or_default();
+                let rows = present + stat["null_total"].unwrap_or_default();
+                let score = if rows > 0.0 { present / rows } else { 0.0 };
+                (name, score)
+            })
+            .collect())
+    }
+
+    /// Returns the numeric columns whose absolute skewness exceeds `threshold`.
+    pub async fn detect_skewed_columns(&self, threshold: f64) -> anyhow::Result<Vec<String>> {
+        let stats = self
+            .scoped(vec![DescribeMethod::Skewness])?
+            .describe_map()
+            .await?;
+
+        Ok(self
+            .pre_transformed()?
+            .schema()
+            .fields()
+            .iter()
+            .filter(|f| f.data_type().is_numeric())
+            .filter(|f| {
+                let skewness = stats.get(f.name()).and_then(|stat| stat["skewness"]);
+                matches!(skewness, Some(v) if v.abs() > threshold)
+            })
+            .map(|f| f.name().to_string())
+            .collect())
+    }
+
+    /// Returns the numeric columns where more than `min_fraction` of the rows are
+    /// outliers by `method`. Nulls are never outliers, but count as rows.
+    pub async fn detect_outlier_columns(
+        &self,
+        method: OutlierMethod,
+        min_fraction: f64,
+    ) -> anyhow::Result<Vec<String>> {
+        if !(0.0..=1.0).contains(&min_fraction) {
+            anyhow::bail!("min_fraction must be within [0, 1], got {}", min_fraction);
+        }
+        let methods = match method {
+            OutlierMethod::Iqr(_) => vec![
+                DescribeMethod::Percentile(25),
+                DescribeMethod::Percentile(75),
+            ],
+            OutlierMethod::ZScore(_) => vec![DescribeMethod::Mean, DescribeMethod::Stddev],
+        };
+        let stats = self.scoped(methods)?.describe_map().await?;
+
+        let mut columns = vec![];
+        let df = self.pre_transformed()?;
+        let mut aggr_expr = vec![count(lit(1)).alias("__rows")];
+        for f in df.schema().fields() {
+            let Some(stat) = stats.get(f.name()).filter(|_| f.data_type().is_numeric()) else {
+                continue;
+            };
+            let bounds = match method {
+                OutlierMethod::Iqr(k) => match (stat["percentile_25"], stat["percentile_75"]) {
+                    (Some(q1), Some(q3)) => Some((q1 - k * (q3 - q1), q3 + k * (q3 - q1))),
+                    _ => None,
+                },
+                OutlierMethod::ZScore(z) => match (stat["mean"], stat["stddev"]) {
+                    (Some(mean), Some(stddev)) => Some((mean - z * stddev, mean + z * stddev)),
+                    _ => None,
+                },
+            };
+            let Some((lower, upper)) = bounds else {
+                continue;
+            };
+            let value = cast(ident(f.name()), DataType::Float64);
+            let is_outlier = value.clone().lt(lit(lower)).or(value.gt(lit(upper)));
+            aggr_expr.push(
+                sum(case(is_outlier)
+                    .when(lit(true), lit(1i64))
+                    .otherwise(lit(0i64))?)
+                .alias(f.name()),
+            );
+            columns.push(f.name().to_string());
+        }
+        let counts = collect_single_row(df.aggregate(vec![], aggr_expr)?).await?;
+
+        let rows = counts["__rows"].unwrap_or(0.0);
+        Ok(columns
+            .into_iter()
+            .filter(|name| matches!(counts[name], Some(n) if rows > 0.0 && n / rows > min_fraction))
+            .collect())
+    }
+
+    /// Returns the numeric columns with at least `n_modes` modes. Each column is
+    /// bucketed into a histogram (Sturges' rule for the bin count); a mode is a
+    /// bin whose count is a local maximum and above the average bin count.
+    pub async fn detect_multimodal_columns(&self, n_modes: usize) -> anyhow::Result<Vec<String>> {
+        if n_modes < 2 {
+            anyhow::bail!("n_modes must be at least 2, got {}", n_modes);
+        }
+        let stats = self
+            .scoped(vec![
+                DescribeMethod::Total,
+                DescribeMethod::Min,
+                DescribeMethod::Max,
+            ])?
+            .describe_map()
+            .await?;
+
+        let mut ret = vec![];
+        for f in self.pre_transformed()?.schema().fields() {
+            let Some(stat) = stats.get(f.name()).filter(|_| f.data_type().is_numeric()) else {
+                continue;
+            };
+            let (Some(total), Some(min), Some(max)) = (stat["total"], stat["min"], stat["max"])
+            else {
+                continue;
+            };
+            if max <= min {
+                continue;
+            }
+            let bins = sturges_bins(total).max(2);
+            let counts = self.histogram(f.name(), min, max, bins).await?;
+            if count_modes(&counts) >= n_modes {
+                ret.push(f.name().to_string());
+            }
+        }
+        Ok(ret)
+    }
+
+    /// Returns a `(bin_lower, bin_upper, count, frequency)` histogram of the numeric
+    /// column `col` over equal-width bins between its min and max, with the bin
+    /// count chosen by Sturges' rule, `ceil(log2(n) + 1)`. `frequency` is the
+    /// share of non-null values in the bin.
+    pub async fn auto_bin_continuous(&self, col: &str) -> anyhow::Result<DataFrame> {
+        self.check_numeric(col)?;
+        let stats = self
+            .scoped(vec![
+                DescribeMethod::Total,
+                DescribeMethod::Min,
+                DescribeMethod::Max,
+            ])?
+            .describe_map()
+            .await?;
+        let stat = described(&stats, col)?;
+        let (Some(total), Some(min), Some(max)) = (stat["total"], stat["min"], stat["max"]) else {
+            anyhow::bail!("column {} has no non-null values", col);
+        };
+
+        let bins = sturges_bins(total);
+        let counts = self.histogram(col, min, max, bins).await?;
+        let width = (max - min) / bins as f64;
+        let lower = (0..bins).map(|i| min + width * i as f64);
+        let upper = (0..bins).map(|i| {
+            if i + 1 == bins {
+                max
+            } else {
+                min + width * (i + 1) as f64
+            }
+        });
+
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("bin_lower", DataType::Float64, false),
+            Field::new("bin_upper", DataType::Float64, false),
+            Field::new("count", DataType::Int64, false),
+            Field::new("frequency", DataType::Float64, false),
+        ]));
+        let batch = RecordBatch::try_new(
+            schema,
+            vec![
+                Arc::new(Float64Array::from_iter_values(lower)) as ArrayRef,
+                Arc::new(Float64Array::from_iter_values(upper)),
+                Arc::new(Int64Array::from(counts.clone())),
+                Arc::new(Float64Array::from_iter_values(
+                    counts.iter().map(|n| *n as f64 / total),
+                )),
+            ],
+        )?;
+        Ok(SessionContext::new().read_batch(batch)?)
+    }
+
+    /// Counts of the non-null values of `col` in `bins` equal-width bins between
+    /// `min` and `max`; a zero range puts everything in the first bin.
+    async fn histogram(
+        &self,
+        col: &str,
+        min: f64,
+        max: f64,
+        bins: usize,
+    ) -> anyhow::Result<Vec<i64>> {
+        let range = if max > min { max - min } else { 1.0 };
+        let bin = cast(
+            (cast(ident(col), DataType::Float64) - lit(min)) / lit(range) * lit(bins as f64),
+            DataType::Int64,
+        );
+        let batches = self
+            .pre_transformed()?
+            .filter(ident(col).is_not_null())?
+            .aggregate(vec![bin.alias("bin")], vec![count(lit(1)).alias("n")])?
+            .collect()
+            .await?;
+
+        let mut counts = vec![0; bins];
+        for batch in batches {
+            let idx = batch.column(0).as_any().downcast_ref::<Int64Array>();
+            let n = batch.column(1).as_any().downcast_ref::<Int64Array>();
+            let (Some(idx), Some(n)) = (idx, n) else {
+                anyhow::bail!("unexpected histogram types for column {}", col);
+            };
+            for (i, n) in idx.values().iter().zip(n.values()) {
+                // the maximum falls on the upper edge of the last bin, clamp the
+                // signed index so values outside the bounds can't wrap around
+                counts[(*i).clamp(0, bins as i64 - 1) as usize] += *n;
+            }
+        }
+        Ok(counts)
+    }
+
+    /// Kullback-Leibler divergence `sum(p * ln(p / q))` of the distribution `p` of the
+    /// numeric column `col` from its distribution `q` in `reference`, e.g. to detect
+    /// drift from a training set. Both are histograms over the same `bins`
+    /// equal-width bins spanning the joint min and max, Laplace smoothed (one
+    /// extra count per bin) so empty bins don't make the divergence infinite.
+    pub async fn kl_divergence(
+        &self,
+        reference: &DataFrameDescriber,
+        col: &str,
+        bins: u32,
+    ) -> anyhow::Result<f64> {
+        let (p, q) = self.joint_histograms(reference, col, bins).await?;
+        let (p, q) = (laplace_smoothed(&p), laplace_smoothed(&q));
+        Ok(p.iter().zip(&q).map(|(p, q)| p * (p / q).ln()).sum())
+    }
+
+    /// Population stability index `sum((actual - expected) * ln(actual / expected))`
+    /// of the numeric column `col`, with `actual` its bin frequencies in `self` and
+    /// `expected` those in `reference`, binned and smoothed as in
+    /// [`Self::kl_divergence`]. See [`PopulationShift::from_psi`] to interpret it.
+    pub async fn population_stability_index(
+        &self,
+        reference: &DataFrameDescriber,
+        col: &str,
+        bins: u32,
+    ) -> anyhow::Result<f64> {
+        let (actual, expected) = self.joint_histograms(reference, col, bins).await?;
+        let (actual, expected) = (laplace_smoothed(&actual), laplace_smoothed(&expected));
+        Ok(actual
+            .iter()
+            .zip(&expected)
+            .map(|(a, e)| (a - e) * (a / e).ln())
+            .sum())
+    }
+
+    /// Histograms of the numeric column `col` in `self` and `reference` over the
+    /// same `bins` equal-width bins between the joint min and max.
+    async fn joint_histograms(
+        &self,
+        reference: &DataFrameDescriber,
+        col: &str,
+        bins: u32,
+    ) -> anyhow::Result<(Vec<i64>, Vec<i64>)> {
+        if bins == 0 {
+            anyhow::bail!("bins must be positive");
+        }
+        let mut bounds = vec![];
+        for describer in [self, reference] {
+            let df = describer.pre_transformed()?;
+            let field = df.schema().field_with_unqualified_name(col)?;
+            if !field.data_type().is_numeric() {
+                anyhow::bail!("column {} is not numeric", col);
+            }
+            let x = cast(ident(col), DataType::Float64);
+            let stats = collect_single_row(df.aggregate(
+                vec![],
+                vec![min(x.clone()).alias("min"), max(x).alias("max")],
+            )?)
+            .await?;
+            if let (Some(lo), Some(hi)) = (stats["min"], stats["max"]) {
+                bounds.push((lo, hi));
+            }
+        }
+        let (Some(lo), Some(hi)) = (
+            bounds.iter().map(|b| b.0).reduce(f64::min),
+            bounds.iter().map(|b| b.1).reduce(f64::max),
+        ) else {
+            anyhow::bail!("column {} has no values to compare", col);
+        };
+
+        let bins = bins as usize;
+        Ok((
+            self.histogram(col, lo, hi, bins).await?,
+            reference.histogram(col, lo, hi, bins).await?,
+        ))
+    }
+
+    /// Returns the number of values of the string column `col` matching the
+    /// regular expression `pattern`. Nulls never match.
+    pub async fn count_regex_match(&self, col: &str, pattern: &str) -> anyhow::Result<u64> {
+        let field = self.original.schema().field_with_unqualified_name(col)?;
+        if !matches!(field.data_type(), DataType::Utf8 | DataType::LargeUtf8) {
+            anyhow::bail!("column {} is not a string column", col);
+        }
+        let matched = self
+            .original
+            .clone()
+            .filter(regexp_like(ident(col), lit(pattern)))?
+            .count()
+            .await
+            .with_context(|| format!("failed to match column {} against {}", col, pattern))?;
+        Ok(matched as u64)
+    }
+
+    /// Returns a `(value, count)` DataFrame with the number of occurrences of every
+    /// value of `col` (nulls included), most frequent first, ties by value.
+    pub async fn value_counts(&self, col: &str) -> anyhow::Result<DataFrame> {
+        self.original.schema().field_with_unqualified_name(col)?;
+        Ok(self
+            .original
+            .clone()
+            .aggregate(
+                vec![ident(col).alias("value")],
+                vec![count(lit(1)).alias("count")],
+            )?
+            .sort(vec![
+                ident("count").sort(false, false),
+                ident("value").sort(true, false),
+            ])?)
+    }
+
+    /// Returns the sorted distinct (non-null) values, as strings, of every column
+    /// with fewer than `max_cardinality` distinct values. Nested columns are skipped.
+    pub async fn distinct_values(
+        &self,
+        max_cardinality: usize,
+    ) -> anyhow::Result<HashMap<String, Vec<String>>> {
+        let fields = self
+            .original
+            .schema()
+            .fields()
+            .iter()
+            .filter(|f| !f.data_type().is_nested())
+            .cloned()
+            .collect::<Vec<_>>();
+        if fields.is_empty() {
+            return Ok(HashMap::new());
+        }
+        let aggr_expr = fields
+            .iter()
+            .map(|f| count_distinct(ident(f.name())).alias(f.name()))
+            .collect();
+        let cardinality =
+            collect_single_row(self.original.clone().aggregate(vec![], aggr_expr)?).await?;
+
+        let mut ret = HashMap::new();
+        for f in fields {
+            if !matches!(cardinality[f.name()], Some(n) if (n as usize) < max_cardinality) {
+                continue;
+            }
+            let batches = self
+                .original
+                .clone()
+                .select(vec![ident(f.name())])?
+                .filter(ident(f.name()).is_not_null())?
+                .distinct()?
+                .sort(vec![ident(f.name()).sort(true, false)])?
+                .collect()
+                .await?;
+            let mut values = vec![];
+            for batch in batches {
+                for i in 0..batch.num_rows() {
+                    values.push(array_value_to_string(batch.column(0), i)?);
+                }
+            }
+            ret.insert(f.name().to_string(), values);
+        }
+        Ok(ret)
+    }
+
+    /// Returns whether the min and max of `col` both fall within
+    /// `[min_expected, max_expected]`.
+    pub async fn value_range_check(
+        &self,
+        col: &str,
+        min_expected: f64,
+        max_expected: f64,
+    ) -> anyhow::Result<bool> {
+        let error = |reason: &str| RangeCheckError {
+            column: col.to_string(),
+            reason: reason.to_string(),
+        };
+        let stats = self
+            .scoped(vec![DescribeMethod::Min, DescribeMethod::Max])?
+            .describe_map()
+            .await?;
+        let stat = stats.get(col).ok_or_else(|| error("column not found"))?;
+        let (Some(min), Some(max)) = (stat["min"], stat["max"]) else {
+            return Err(error("min/max is null").into());
+        };
+
+        let range = min_expected..=max_expected;
+        Ok(range.contains(&min) && range.contains(&max))
+    }
+
+    /// Checks the ratio `P{high_p} / P{low_p}` of `col` is within `tolerance` of
+    /// `expected_ratio`, e.g. to verify a data contract like `P90 / P10 ≈ 5`.
+    pub async fn percentile_range_check(
+        &self,
+        col: &str,
+        low_p: u8,
+        high_p: u8,
+        expected_ratio: f64,
+        tolerance: f64,
+    ) -> anyhow::Result<bool> {
+        self.check_numeric(col)?;
+        let (low, high) = (
+            DescribeMethod::Percentile(low_p),
+            DescribeMethod::Percentile(high_p),
+        );
+        let stats = self
+            .scoped(vec![low.clone(), high.clone()])?
+            .describe_map()
+            .await?;
+        let stat = described(&stats, col)?;
+        let (Some(low), Some(high)) = (stat[&low.to_string()], stat[&high.to_string()]) else {
+            anyhow::bail!("percentiles of column {} are null", col);
+        };
+        if low == 0.0 {
+            anyhow::bail!("percentile_{} of column {} is zero", low_p, col);
+        }
+        Ok((high / low - expected_ratio).abs() <= tolerance)
+    }
+
+    /// Exact weighted `p` quantile (`p` within `[0, 1]`) of the numeric column `col`
+    /// weighted by the numeric column `weight_col`, e.g. for survey data.
+    ///
+    /// The weights of equal values are summed, the distinct values are sorted
+    /// and the result is the smallest value whose cumulative weight reaches the
+    /// fraction `p` of the total weight, i.e. the inverse of the weighted CDF
+    /// without interpolation. Rows with a null value or a null or non-positive
+    /// weight are ignored.
+    pub async fn weighted_percentile(
+        &self,
+        col: &str,
+        weight_col: &str,
+        p: f64,
+    ) -> anyhow::Result<f64> {
+        if !(0.0..=1.0).contains(&p) {
+            anyhow::bail!("percentile fraction must be within [0, 1], got {}", p);
+        }
+        for name in [col, weight_col] {
+            let field = self.original.schema().field_with_unqualified_name(name)?;
+            if !field.data_type().is_numeric() {
+                anyhow::bail!("column {} is not numeric", name);
+            }
+        }
+        let (x, w) = (
+            cast(ident(col), DataType::Float64),
+            cast(ident(weight_col), DataType::Float64),
+        );
+        let batches = self
+            .original
+            .clone()
+            .filter(x.clone().is_not_null().and(w.clone().gt(lit(0.0))))?
+            .aggregate(vec![x.alias("value")], vec![sum(w).alias("weight")])?
+            .sort(vec![ident("value").sort(true, false)])?
+            .collect()
+            .await?;
+
+        let mut values = vec![];
+        for batch in &batches {
+            let value = batch.column(0).as_any().downcast_ref::<Float64Array>();
+            let weight = batch.column(1).as_any().downcast_ref::<Float64Array>();
+            let (Some(value), Some(weight)) = (value, weight) else {
+                anyhow::bail!("unexpected weighted percentile types for column {}", col);
+            };
+            values.extend(
+                value
+                    .values()
+                    .iter()
+                    .copied()
+                    .zip(weight.values().iter().copied()),
+            );
+        }
+        let total = values.iter().map(|(_, w)| w).sum::<f64>();
+        if total <= 0.0 {
+            anyhow::bail!("column {} has no positively weighted values", col);
+        }
+
+        let mut cumulative = 0.0;
+        for (value, weight) in &values {
+            cumulative += weight;
+            if cumulative >= p * total {
+                return Ok(*value);
+            }
+        }
+        // rounding can leave the cumulative weight just short of the total
+        Ok(values.last().map(|(v, _)| *v).unwrap_or(f64::NAN))
+    }
+
+    /// Returns the `(lower, upper)` confidence interval of the mean of `col`, i.e.
+    /// `mean ± q * stddev / sqrt(n)`, where `q` is the normal quantile for
+    /// `confidence` (e.g. 1.96 for 0.95), or the Student's t quantile when `n < 30`.
+    /// Both quantiles are numerical approximations, accurate to about 1e-9.
+    pub async fn confidence_interval(
+        &self,
+        col: &str,
+        confidence: f64,
+    ) -> anyhow::Result<(f64, f64)> {
+        if !(confidence > 0.0 && confidence < 1.0) {
+            anyhow::bail!("confidence must be within (0, 1), got {}", confidence);
+        }
+        self.check_numeric(col)?;
+        let stats = self
+            .scoped(vec![
+                DescribeMethod::Total,
+                DescribeMethod::Mean,
+                DescribeMethod::Stddev,
+            ])?
+            .describe_map()
+            .await?;
+        let stat = described(&stats, col)?;
+        let (Some(n), Some(mean), Some(stddev)) = (stat["total"], stat["mean"], stat["stddev"])
+        else {
+            anyhow::bail!("column {} needs at least two non-null values", col);
+        };
+
+        let p = 0.5 + confidence / 2.0;
+        let q = if n < 30.0 {
+            t_quantile(p, n - 1.0)
+        } else {
+            normal_quantile(p)
+        };
+        let margin = q * stddev / n.sqrt();
+        Ok((mean - margin, mean + margin))
+    }
+
+    /// Heuristically flags columns that may hold personal data:
+    /// high-cardinality string columns (potential identifiers), columns named
+    /// like `email`/`phone`/`ssn`/`dob`, and numeric columns whose values are all
+    /// whole numbers with nine digits, like SSNs.
+    ///
+    /// The SSN check is narrower than the SSN range 000000000–999999999: values
+    /// with leading zeros (below 100000000) don't count, as otherwise any column
+    /// of small non-negative integers with one large value would be flagged.
+    pub async fn sensitivity_report(&self) -> anyhow::Result<Vec<SensitivityFlag>> {
+        let fields = self.original.schema().fields().clone();
+        let key = |name: &str, stat: &str| format!("{}__{}", name, stat);
+
+        let mut aggr_expr = vec![];
+        for f in fields.iter() {
+            let dt = f.data_type();
+            if matches!(dt, DataType::Utf8 | DataType::LargeUtf8) {
+                aggr_expr.push(count_distinct(ident(f.name())).alias(key(f.name(), "distinct")));
+                aggr_expr.push(count(ident(f.name())).alias(key(f.name(), "count")));
+            } else if dt.is_numeric() {
+                aggr_expr.push(min(ident(f.name())).alias(key(f.name(), "min")));
+                aggr_expr.push(max(ident(f.name())).alias(key(f.name(), "max")));
+                if !dt.is_integer() {
+                    let fractional =
+                        (cast(ident(f.name()), DataType::Float64) % lit(1.0)).not_eq(lit(0.0));
+                    aggr_expr.push(
+                        sum(case(fractional)
+                            .when(lit(true), lit(1i64))
+                            .otherwise(lit(0i64))?)
+                        .alias(key(f.name(), "fractional")),
+                    );
+                }
+            }
+        }
+        let stats = if aggr_expr.is_empty() {
+            HashMap::new()
+        } else {
+            collect_single_row(self.original.clone().aggregate(vec![], aggr_expr)?).await?
+        };
+        let stat = |name: &str, s: &str| stats.get(&key(name, s)).copied().flatten();
+
+        let mut flags = vec![];
+        for f in fields.iter() {
+            let mut flag = |reason: String| {
+                flags.push(SensitivityFlag {
+                    column: f.name().to_string(),
+                    reason,
+                })
+            };
+            let lower = f.name().to_lowercase();
+            if let Some(hint) = SENSITIVE_NAMES.iter().find(|n| lower.contains(*n)) {
+                flag(format!("column name contains \"{}\"", hint));
+            }
+            if let (Some(distinct), Some(count)) =
+                (stat(f.name(), "distinct"), stat(f.name(), "count"))
+            {
+                if count > 0.0 && distinct / count > HIGH_CARDINALITY_RATIO {
+                    flag(format!(
+                        "high-cardinality string column ({} distinct of {} values), potential identifier",
+                        distinct, count
+                    ));
+                }
+            }
+            if let (Some(min), Some(max)) = (stat(f.name(), "min"), stat(f.name(), "max")) {
+                // every value is a whole number with nine digits
+                let whole = stat(f.name(), "fractional").unwrap_or_default() == 0.0;
+                if whole && min >= 100_000_000.0 && max <= 999_999_999.0 {
+                    flag("whole numbers within the nine-digit SSN range".to_string());
+                }
+            }
+        }
+        Ok(flags)
+    }
+
+    /// The original rows with the per-row statistics `row_null_count`,
+    /// `row_numeric_sum` and `row_numeric_mean` appended. The sum and mean skip
+    /// null values; the mean is null for rows without any numeric value.
+    pub fn row_statistics(&self) -> anyhow::Result<DataFrame> {
+        let fields = self.original.schema().fields().clone();
+        let indicator = |e: Expr| case(e).when(lit(true), lit(1i64)).otherwise(lit(0i64));
+        let mut nulls = lit(0i64);
+        let mut total = lit(0.0);
+        let mut present = lit(0i64);
+        for f in fields.iter() {
+            let x = ident(f.name());
+            nulls = nulls + indicator(x.clone().is_null())?;
+            if f.data_type().is_numeric() {
+                total = total + coalesce(vec![cast(x.clone(), DataType::Float64), lit(0.0)]);
+                present = present + indicator(x.is_not_null())?;
+            }
+        }
+        let mean = case(present.clone().eq(lit(0i64)))
+            .when(lit(true), lit(ScalarValue::Float64(None)))
+            .otherwise(total.clone() / cast(present, DataType::Float64))?;
+
+        let mut expressions = fields.iter().map(|f| ident(f.name())).collect::<Vec<_>>();
+        expressions.push(nulls.alias("row_null_count"));
+        expressions.push(total.alias("row_numeric_sum"));
+        expressions.push(mean.alias("row_numeric_mean"));
+        Ok(self.original.clone().select(expressions)?)
+    }
+
+    /// Returns `n_rows` rows of random data with the schema of the original:
+    /// numeric columns are drawn from a normal distribution with the column's
+    /// mean and standard deviation (rounded for integer columns), all other columns
+    /// are null (and made nullable).
+    pub async fn generate_synthetic_data(&self, n_rows: usize) -> anyhow::Result<DataFrame> {
+        let stats = self
+            .scoped(vec![DescribeMethod::Mean, DescribeMethod::Stddev])?
+            .describe_map()
+            .await?;
+        let mut rng = rand::thread_rng();
+
+        let mut fields = vec![];
+        let mut columns = vec![];
+        for f in self.unweighted_original()?.schema().fields() {
+            let Some(stat) = stats.get(f.name()).filter(|_| f.data_type().is_numeric()) else {
+                fields.push(f.as_ref().clone().with_nullable(true));
+                columns.push(arrow::array::new_null_array(f.data_type(), n_rows));
+                continue;
+            };
+            let values: Float64Array = match (stat["mean"], stat["stddev"]) {
+                (Some(mean), stddev) => {
+                    let normal = Normal::new(mean, stddev.unwrap_or_default())?;
+                    let integer = f.data_type().is_integer();
+                    (0..n_rows)
+                        .map(|_| {
+                            let x = normal.sample(&mut rng);
+                            // round rather than truncate toward zero on the cast
+                            Some(if integer { x.round() } else { x })
+                        })
+                        .collect()
+                }
+                (None, _) => (0..n_rows).map(|_| None).collect(),
+            };
+            fields.push(f.as_ref().clone());
+            columns.push(cast_array(&values, f.data_type())?);
+        }
+        let batch = RecordBatch::try_new(Arc::new(Schema::new(fields)), columns)?;
+        Ok(SessionContext::new().read_batch(batch)?)
+    }
+
+    /// Checks whether `col` is monotone, comparing each non-null value with the
+    /// previous non-null one in the input ordering (see [`DescribeMethod::First`]).
+    pub async fn monotonicity_check(&self, col: &str) -> anyhow::Result<MonotonicityResult> {
+        let df = self.unweighted_original()?;
+        df.schema().field_with_unqualified_name(col)?;
+        let x = ident("x");
+        let mut expressions = vec![ident(col).alias("x")];
+        let (keys, order_by) = self.order_keys();
+        expressions.extend(keys);
+        let previous = Expr::WindowFunction(expr::WindowFunction::new(
+            WindowFunctionDefinition::BuiltInWindowFunction(BuiltInWindowFunction::Lag),
+            vec![x.clone()],
+            vec![],
+            order_by,
+            WindowFrame::new(None),
+            None,
+        ));
+        let steps = |e: Expr| {
+            sum(case(e)
+                .when(lit(true), lit(1i64))
+                .otherwise(lit(0i64))
+                .unwrap())
+        };
+        let stats = df
+            .select(expressions)?
+            .filter(x.clone().is_not_null())?
+            .window(vec![previous.alias("previous")])?
+            .aggregate(
+                vec![],
+                vec![
+                    steps(x.clone().lt(ident("previous"))).alias("decreasing"),
+                    steps(x.gt(ident("previous"))).alias("increasing"),
+                ],
+            )?;
+        let stats = collect_single_row(stats).await?;
+        let decreasing = stats["decreasing"].unwrap_or_default() as u64;
+        let increasing = stats["increasing"].unwrap_or_default() as u64;
+        Ok(MonotonicityResult {
+            is_monotone_increasing: decreasing == 0,
+            is_monotone_decreasing: increasing == 0,
+            violations: decreasing.min(increasing),
+        })
+    }
+
+    /// Flags string columns whose values are mostly (over 90% of the non-null
+    /// values) numbers, e.g. a price column with the odd `"N/A"`, as Float64.
+    pub async fn detect_data_type_mismatch(&self) -> anyhow::Result<Vec<TypeMismatch>> {
+        let fields = self
+            .original
+            .schema()
+            .fields()
+            .iter()
+            .filter(|f| matches!(f.data_type(), DataType::Utf8 | DataType::LargeUtf8))
+            .cloned()
+            .collect::<Vec<_>>();
+        if fields.is_empty() {
+            return Ok(vec![]);
+        }
+        let key = |name: &str, stat: &str| format!("{}__{}", name, stat);
+
+        let mut aggr_expr = vec![];
+        for f in &fields {
+            aggr_expr.push(count(ident(f.name())).alias(key(f.name(), "count")));
+            aggr_expr.push(
+                count(try_cast(ident(f.name()), DataType::Float64)).alias(key(f.name(), "parsed")),
+            );
+        }
+        let stats = collect_single_row(self.original.clone().aggregate(vec![], aggr_expr)?).await?;
+        let stat = |name: &str, s: &str| stats.get(&key(name, s)).copied().flatten();
+
+        let mut ret = vec![];
+        for f in &fields {
+            if let (Some(count), Some(parsed)) = (stat(f.name(), "count"), stat(f.name(), "parsed"))
+            {
+                if count > 0.0 && parsed / count > NUMERIC_STRING_RATIO {
+                    ret.push(TypeMismatch {
+                        column: f.name().to_string(),
+                        declared_type: f.data_type().clone(),
+                        inferred_type: DataType::Float64,
+                    });
+                }
+            }
+        }
+        Ok(ret)
+    }
+
+    /// Reports which types the values of the string column `col` parse as, over a
+    /// sample of its first 10,000 rows. Fractions are of the
+    /// non-null sampled values, all 0 if there are none.
+    pub async fn detect_mixed_type_string_columns(
+        &self,
+        col: &str,
+    ) -> anyhow::Result<MixedTypeReport> {
+        let field = self.original.schema().field_with_unqualified_name(col)?;
+        if !matches!(field.data_type(), DataType::Utf8 | DataType::LargeUtf8) {
+            anyhow::bail!("column {} is not a string column", col);
+        }
+        let value = ident(col);
+        let parsed = |dt: DataType| try_cast(value.clone(), dt).is_not_null();
+        let indicator = |e: Expr| {
+            sum(case(e)
+                .when(lit(true), lit(1i64))
+                .otherwise(lit(0i64))
+                .unwrap())
+        };
+        let aggr_expr = vec![
+            count(value.clone()).alias("count"),
+            indicator(parsed(DataType::Float64)).alias("numeric"),
+            indicator(parsed(DataType::Date32)).alias("date"),
+            indicator(parsed(DataType::Boolean)).alias("boolean"),
+            indicator(
+                value.clone().is_not_null().and(
+                    parsed(DataType::Float64)
+                        .or(parsed(DataType::Date32))
+                        .or(parsed(DataType::Boolean))
+                        .is_false(),
+                ),
+            )
+            .alias("pure_string"),
+        ];
+        let sample = self
+            .original
+            .clone()
+            .limit(0, Some(MIXED_TYPE_SAMPLE_ROWS))?;
+        let stats = collect_single_row(sample.aggregate(vec![], aggr_expr)?).await?;
+
+        let count = stats["count"].unwrap_or(0.0);
+        let fraction = |name: &str| match stats[name] {
+            Some(n) if count > 0.0 => n / count,
+            _ => 0.0,
+        };
+        Ok(MixedTypeReport {
+            numeric_fraction: fraction("numeric"),
+            date_fraction: fraction("date"),
+            boolean_fraction: fraction("boolean"),
+            pure_string_fraction: fraction("pure_string"),
+        })
+    }
+
+    /// Computes `method` over the numeric columns `col_a` and `col_b`.
+    pub async fn pairwise_stat(
+        &self,
+        col_a: &str,
+        col_b: &str,
+        method: PairwiseMethod,
+    ) -> anyhow::Result<f64> {
+        for name in [col_a, col_b] {
+            let field = self.original.schema().field_with_unqualified_name(name)?;
+            if !field.data_type().is_numeric() {
+                anyhow::bail!("column {} is not numeric", name);
+            }
+        }
+
+        let df = self.original.clone();
+        let stat_df = match method {
+            PairwiseMethod::Covariance => covariance(df, col_a, col_b)?,
+            PairwiseMethod::Correlation => correlation(df, col_a, col_b)?,
+        };
+        collect_single_row(stat_df)
+            .await?
+            .into_values()
+            .next()
+            .flatten()
+            .ok_or_else(|| anyhow::anyhow!("{:?} of {} and {} is null", method, col_a, col_b))
+    }
+
+    /// Returns the pairs of numeric columns, in schema order, whose absolute
+    /// Pearson correlation exceeds `threshold`, with their correlation.
+    pub async fn detect_near_duplicate_columns(
+        &self,
+        threshold: f64,
+    ) -> anyhow::Result<Vec<(String, String, f64)>> {
+        Ok(self
+            .pairwise_correlations()
+            .await?
+            .into_iter()
+            .filter(|(_, _, r, _)| r.abs() > threshold)
+            .map(|(a, b, r, _)| (a, b, r))
+            .collect())
+    }
+
+    /// Returns the `n` pairs of distinct numeric columns with the highest absolute
+    /// Pearson correlation, strongest first. Undefined correlations are skipped.
+    pub async fn top_correlations(&self, n: usize) -> anyhow::Result<Vec<(String, String, f64)>> {
+        let mut pairs = self.pairwise_correlations().await?;
+        pairs.sort_by(|a, b| b.2.abs().total_cmp(&a.2.abs()));
+        Ok(pairs
+            .into_iter()
+            .take(n)
+            .map(|(a, b, r, _)| (a, b, r))
+            .collect())
+    }
+
+    /// Returns a `(col_a, col_b, correlation, p_value, significant)` DataFrame with
+    /// the Pearson correlation of every pair of numeric columns and the two-sided
+    /// p-value of it being non-zero, approximated by the Fisher z-transformation.
+    /// `significant` is `p_value < alpha`; the p-value is null for pairs with fewer
+    /// than four complete rows.
+    pub async fn correlation_significance(&self, alpha: f64) -> anyhow::Result<DataFrame> {
+        if !(alpha > 0.0 && alpha < 1.0) {
+            anyhow::bail!("alpha must be within (0, 1), got {}", alpha);
+        }
+        let pairs = self.pairwise_correlations().await?;
+        let p_values = pairs
+            .iter()
+            .map(|(_, _, r, n)| {
+                if *n < 4 {
+                    return None;
+                }
+                let z = r.clamp(-1.0, 1.0).atanh() * (*n as f64 - 3.0).sqrt();
+                Some(2.0 * (1.0 - normal_cdf(z.abs())))
+            })
+            .collect::<Vec<_>>();
+
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("col_a", DataType::Utf8, false),
+            Field::new("col_b", DataType::Utf8, false),
+            Field::new("correlation", DataType::Float64, false),
+            Field::new("p_value", DataType::Float64, true),
+            Field::new("significant", DataType::Boolean, false),
+        ]));
+        let batch = RecordBatch::try_new(
+            schema,
+            vec![
+                Arc::new(StringArray::from_iter_values(pairs.iter().map(|p| &p.0))) as ArrayRef,
+                Arc::new(StringArray::from_iter_values(pairs.iter().map(|p| &p.1))),
+                Arc::new(Float64Array::from_iter_values(pairs.iter().map(|p| p.2))),
+                Arc::new(Float64Array::from(p_values.clone())),
+                Arc::new(BooleanArray::from(
+                    p_values
+                        .iter()
+                        .map(|p| matches!(p, Some(p) if *p < alpha))
+                        .collect::<Vec<_>>(),
+                )),
+            ],
+        )?;
+        Ok(SessionContext::new().read_batch(batch)?)
+    }
+
+    /// Pearson correlation of every pair of numeric columns, in schema order, with
+    /// the number of rows where both are non-null. Undefined correlations (e.g.
+    /// constant columns) are skipped.
+    async fn pairwise_correlations(&self) -> anyhow::Result<Vec<(String, String, f64, u64)>> {
+        let numeric = self
+            .original
+            .schema()
+            .fields()
+            .iter()
+            .filter(|f| f.data_type().is_numeric())
+            .map(|f| f.name().to_string())
+            .collect::<Vec<_>>();
+        let pairs = numeric
+            .iter()
+            .enumerate()
+            .flat_map(|(i, a)| numeric[i + 1..].iter().map(move |b| (a, b)))
+            .collect::<Vec<_>>();
+        if pairs.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let key = |i: usize| format!("pair__{}", i);
+        let count_key = |i: usize| format!("count__{}", i);
+        let aggr_expr = pairs
+            .iter()
+            .enumerate()
+            .flat_map(|(i, (a, b))| {
+                let (a, b) = (
+                    cast(ident(*a), DataType::Float64),
+                    cast(ident(*b), DataType::Float64),
+                );
+                [
+                    corr(a.clone(), b.clone()).alias(key(i)),
+                    // the sum is null unless both are non-null
+                    count(a + b).alias(count_key(i)),
+                ]
+            })
+            .collect();
+        let stats = collect_single_row(self.original.clone().aggregate(vec![], aggr_expr)?).await?;
+
+        Ok(pairs
+            .into_iter()
+            .enumerate()
+            .filter_map(|(i, (a, b))| match stats[&key(i)] {
+                Some(r) if !r.is_nan() => {
+                    let n = stats[&count_key(i)].unwrap_or(0.0) as u64;
+                    Some((a.clone(), b.clone(), r, n))
+                }
+                _ => None,
+            })
+            .collect())
+    }
+
+    /// Returns a `(column_name, correlation)` DataFrame with the Pearson correlation
+    /// of every other numeric column with `target`, sorted by absolute correlation
+    /// (descending). Undefined correlations (e.g. constant columns) are null and last.
+    pub async fn column_correlation_with_target(&self, target: &str) -> anyhow::Result<DataFrame> {
+        let field = self.original.schema().field_with_unqualified_name(target)?;
+        if !field.data_type().is_numeric() {
+            anyhow::bail!("column {} is not numeric", target);
+        }
+
+        let features = self
+            .original
+            .schema()
+            .fields()
+            .iter()
+            .filter(|f| f.data_type().is_numeric() && f.name() != target)
+            .map(|f| f.name().to_string())
+            .collect::<Vec<_>>();
+        let mut rows = if features.is_empty() {
+            vec![]
+        } else {
+            let aggr_expr = features
+                .iter()
+                .map(|name| {
+                    corr(
+                        cast(ident(name), DataType::Float64),
+                        cast(ident(target), DataType::Float64),
+                    )
+                    .alias(name)
+                })
+                .collect();
+            let stats =
+                collect_single_row(self.original.clone().aggregate(vec![], aggr_expr)?).await?;
+            features
+                .into_iter()
+                .map(|name| {
+                    let value = stats[&name].filter(|v| !v.is_nan());
+                    (name, value)
+                })
+                .collect()
+        };
+        rows.sort_by(|a, b| match (a.1, b.1) {
+            (Some(a), Some(b)) => b.abs().total_cmp(&a.abs()),
+            (a, b) => b.is_some().cmp(&a.is_some()),
+        });
+
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("column_name", DataType::Utf8, false),
+            Field::new("correlation", DataType::Float64, true),
+        ]));
+        let batch = RecordBatch::try_new(
+            schema,
+            vec![
+                Arc::new(StringArray::from_iter_values(rows.iter().map(|r| &r.0))) as ArrayRef,
+                Arc::new(Float64Array::from_iter(rows.iter().map(|r| r.1))),
+            ],
+        )?;
+        Ok(SessionContext::new().read_batch(batch)?)
+    }
+
+    /// Returns the original DataFrame (after `apply_before_describe` transforms) with
+    /// numeric columns min-max normalized to `[0, 1]` as Float64. Constant columns
+    /// become 0.0; other columns, including the weight column, are untouched.
+    pub async fn min_max_scale(&self) -> anyhow::Result<DataFrame> {
+        let stats = self
+            .scoped(vec![DescribeMethod::Min, DescribeMethod::Max])?
+            .describe_map()
+            .await?;
+
+        let df = self.pre_transformed()?;
+        let expressions = df
+            .schema()
+            .fields()
+            .iter()
+            .map(|f| {
+                let Some(stat) = stats.get(f.name()).filter(|_| f.data_type().is_numeric()) else {
+                    return ident(f.name());
+                };
+                let x = cast(ident(f.name()), DataType::Float64);
+                let expr = match (stat["min"], stat["max"]) {
+                    (Some(min), Some(max)) if max > min => (x - lit(min)) / lit(max - min),
+                    // zero range (or all null): keep nulls, emit 0.0 otherwise
+                    _ => x * lit(0.0),
+                };
+                expr.alias(f.name())
+            })
+            .collect::<Vec<_>>();
+
+        Ok(df.select(expressions)?)
+    }
+
+    /// Fails unless `col` is a numeric column of the (pre-transformed) DataFrame.
+    fn check_numeric(&self, col: &str) -> anyhow::Result<()> {
+        let df = self.pre_transformed()?;
+        let field = df.schema().field_with_unqualified_name(col)?;
+        if !field.data_type().is_numeric() {
+            anyhow::bail!("column {} is not numeric", col);
+        }
+        Ok(())
+    }
+
+    /// Build a describer over the same data and configuration, computing only `methods`.
+    fn scoped(&self, methods: Vec<DescribeMethod>) -> anyhow::Result<Self> {
+        Ok(self.derive(self.original.clone())?.with_methods(methods))
+    }
+
+    /// Build a describer for `df` with the same configuration as `self`.
+    fn derive(&self, df: DataFrame) -> anyhow::Result<Self> {
+        let mut ret = Self::try_new(df)?.with_methods(self.methods.clone());
+        ret.percentile_udf = self.percentile_udf.clone();
+        ret.absolute_columns = self.absolute_columns.clone();
+        ret.output_batch_size = self.output_batch_size;
+        ret.weight_col = self.weight_col.clone();
+        ret.fingerprint_decimals = self.fingerprint_decimals;
+        ret.min_count = self.min_count;
+        ret.filter = self.filter.clone();
+        ret.cast_fn = self.cast_fn.clone();
+        ret.fallback_transform = self.fallback_transform.clone();
+        ret.pre_transforms = self.pre_transforms.clone();
+        ret.coerce_numeric_strings = self.coerce_numeric_strings;
+        ret.exact_temporal = self.exact_temporal;
+        ret.median_strategy = self.median_strategy;
+        ret.output_names = self.output_names.clone();
+        ret.cache = self.cache.clone();
+        ret.version_metadata = self.version_metadata;
+        ret.dataset_summary = self.dataset_summary;
+        ret.transformed = ret.transform()?;
+        Ok(ret)
+    }
+
+    /// The `(original, transformed)` rows statistics are computed over. The
+    /// filter applies to the aggregation input only.
+    fn aggregation_input(&self) -> anyhow::Result<(DataFrame, DataFrame)> {
+        Ok(match &self.filter {
+            Some(predicate) => {
+                let filtered = self.flattened()?.filter(predicate.clone())?;
+                (
+                    unweighted(filtered.clone())?,
+                    self.transform_frame(filtered)?,
+                )
+            }
+            None => (self.unweighted_original()?, self.transformed.clone()),
+        })
+    }
+
+    /// Append the `with_dataset_summary` row and columns to the statistics `df`.
+    fn with_summary_row(&self, df: DataFrame) -> anyhow::Result<DataFrame> {
+        if !self.dataset_summary {
+            return Ok(df);
+        }
+        let (source, _) = self.aggregation_input()?;
+        let columns = source.schema().fields().len();
+        let null_key = |name: &str| format!("{}__nulls", name);
+        let mut aggr_expr = vec![count(lit(1)).alias("__rows")];
+        for f in source.schema().fields() {
+            aggr_expr.push(
+                sum(case(is_null(ident(f.name())))
+                    .when(lit(true), lit(1i64))
+                    .otherwise(lit(0i64))?)
+                .alias(null_key(f.name())),
+            );
+        }
+        let rows = cast(ident("__rows"), DataType::Float64);
+        let cells = rows.clone() * lit(columns as f64);
+        let nulls = source
+            .schema()
+            .fields()
+            .iter()
+            .map(|f| cast(ident(null_key(f.name())), DataType::Float64))
+            .fold(lit(0.0), |acc, n| acc + n);
+        let summary = [
+            rows,
+            lit(columns as f64),
+            cells.clone(),
+            when(cells.clone().eq(lit(0.0)), lit(ScalarValue::Float64(None)))
+                .otherwise(nulls * lit(100.0) / cells)?,
+        ];
+
+        let mut select_expr = df
+            .schema()
+            .fields()
+            .iter()
+            .map(|f| match f.name().as_str() {
+                "describe" => lit(DATASET_SUMMARY_LABEL).alias("describe"),
+                name => cast(lit(ScalarValue::Null), f.data_type().clone()).alias(name),
+            })
+            .collect::<Vec<_>>();
+        select_expr.extend(
+            summary
+                .into_iter()
+                .zip(DATASET_SUMMARY_COLUMNS)
+                .map(|(expr, name)| expr.alias(name)),
+        );
+        let summary = source.aggregate(vec![], aggr_expr)?.select(select_expr)?;
+
+        let mut padded = df
+            .schema()
+            .fields()
+            .iter()
+            .map(|f| ident(f.name()))
+            .collect::<Vec<_>>();
+        padded.extend(
+            DATASET_SUMMARY_COLUMNS
+                .iter()
+                .map(|name| lit(ScalarValue::Float64(None)).alias(*name)),
+        );
+        Ok(df.select(padded)?.union(summary)?)
+    }
+
+    fn do_describe(&self) -> anyhow::Result<DataFrame> {
+        let is_weighted = self.weight_col.is_some();
+        let (source, transformed) = self.aggregation_input()?;
+        let exact_temporal = source
+            .schema()
+            .fields()
+            .iter()
+            .filter(|f| self.exact_temporal && f.data_type().is_temporal())
+            .map(|f| f.name().clone())
+            .collect::<Vec<_>>();
+        let df = self
+            .methods
+            .iter()
+            .try_fold(None, |acc: Option<DataFrame>, method| {
+                let weighted = transformed.clone();
+                let df = unweighted(weighted.clone())?;
+                let stat_df = match method {
+                    DescribeMethod::Mean if is_weighted => weighted_mean(weighted)?,
+                    DescribeMethod::Stddev | DescribeMethod::StddevSample if is_weighted => {
+                        weighted_stddev(weighted, false)?
+                    }
+                    DescribeMethod::StddevPop if is_weighted => weighted_stddev(weighted, true)?,
+                    DescribeMethod::Median if is_weighted => weighted_percentile(weighted, 0.5)?,
+                    DescribeMethod::Percentile(p) if is_weighted => {
+                        weighted_percentile(weighted, *p as f64 / 100.0)?
+                    }
+                    DescribeMethod::PercentileF(p) if is_weighted => {
+                        weighted_percentile(weighted, *p)?
+                    }
+                    DescribeMethod::TopPercentile(p) if is_weighted => {
+                        weighted_percentile(weighted, top_fraction(*p)?)?
+                    }
+                    DescribeMethod::Total => total(df)?,
+                    DescribeMethod::NullTotal => null_total(df)?,
+                    DescribeMethod::Mean => mean(df)?,
+                    DescribeMethod::Stddev | DescribeMethod::StddevSample => std_div(df)?,
+                    DescribeMethod::StddevPop => std_div_pop(df)?,
+                    DescribeMethod::Min => minimum(df)?,
+                    DescribeMethod::Max => maximum(df)?,
+                    DescribeMethod::Median => match self.median_strategy {
+                        Some(strategy) => median_with(df, strategy)?,
+                        None => med(df)?,
+                    },
+                    DescribeMethod::Percentile(p) => {
+                        percentile(df, *p as f64 / 100.0, self.percentile_udf.as_deref())?
+                    }
+                    DescribeMethod::PercentileF(p) => {
+                        percentile(df, *p, self.percentile_udf.as_deref())?
+                    }
+                    DescribeMethod::TopPercentile(p) => {
+                        percentile(df, top_fraction(*p)?, self.percentile_udf.as_deref())?
+                    }
+                    // distinct values must be counted on the original, untransformed columns
+                    DescribeMethod::IsConstant => is_constant(source.clone())?,
+                    DescribeMethod::WhitespacePaddedCount => {
+                        whitespace_padded_count(source.clone())?
+                    }
+                    DescribeMethod::Skewness => skewness(df)?,
+                    DescribeMethod::MaxDecimalPlaces => max_decimal_places(source.clone())?,
+                    DescribeMethod::MinAbs => minimum_abs(df)?,
+                    DescribeMethod::MaxAbs => maximum_abs(df)?,
+                    DescribeMethod::Gini => gini(df)?,
+                    DescribeMethod::GiniImpurity => gini_impurity(df)?,
+                    DescribeMethod::First => self.first_last(source.clone(), false)?,
+                    DescribeMethod::Last => self.first_last(source.clone(), true)?,
+                    DescribeMethod::MonotonicViolations => {
+                        self.monotonic_violations(source.clone())?
+                    }
+                    DescribeMethod::HasMixedSign => has_mixed_sign(source.clone())?,
+                    DescribeMethod::RangeUtilization => range_utilization(source.clone())?,
+                    // binary columns are transformed to their length, so use the raw bytes
+                    DescribeMethod::MinBytes => binary_extreme(source.clone(), false)?,
+                    DescribeMethod::MaxBytes => binary_extreme(source.clone(), true)?,
+                    DescribeMethod::EmptyCount => string_fill_count(source.clone(), true)?,
+                    DescribeMethod::NonEmptyCount => string_fill_count(source.clone(), false)?,
+                };
+                let stat_df = match self.min_count {
+                    Some(n) if method.needs_min_count() => {
+                        let df = unweighted(transformed.clone())?;
+                        guard_min_count(stat_df, df, n)?
+                    }
+                    _ => stat_df,
+                };
+                // add a new column to the beginning of the DataFrame
+                let mut select_expr = vec![lit(method.to_string()).alias("describe")];
+                // widen numeric statistics to Float64, so the union won't truncate e.g. the
+                // mean, and exact temporal ones to a decimal holding any Int64
+                select_expr.extend(stat_df.schema().fields().iter().map(|f| {
+                    let expr = if exact_temporal.contains(f.name()) && f.data_type().is_numeric() {
+                        cast(ident(f.name()), DataType::Decimal128(38, 0))
+                    } else if f.data_type().is_numeric() {
+                        cast(ident(f.name()), DataType::Float64)
+                    } else {
+                        ident(f.name())
+                    };
+                    expr.alias(f.name())
+                }));
+
+                let stat_df = stat_df.select(select_expr)?;
+
+                anyhow::Ok(Some(match acc {
+                    Some(acc) => acc.union(stat_df)?,
+                    None => stat_df,
+                }))
+            })?;
+
+        df.ok_or_else(|| anyhow::anyhow!("No statistics found"))
+    }
+
+    /// The columns the original is sorted by as `(column, ascending, nulls_first)`,
+    /// if its plan ends in a sort on plain columns (possibly followed by filters,
+    /// limits and projections keeping those columns).
+    fn input_ordering(&self) -> Vec<(String, bool, bool)> {
+        let mut plan = self.original.logical_plan();
+        let sort = loop {
+            match plan {
+                LogicalPlan::Sort(sort) => break sort,
+                LogicalPlan::Filter(filter) => plan = &filter.input,
+                LogicalPlan::Limit(limit) => plan = &limit.input,
+                LogicalPlan::Projection(projection) => plan = &projection.input,
+                _ => return vec![],
+            }
+        };
+        let mut ordering = vec![];
+        for e in &sort.expr {
+            match e {
+                Expr::Sort(expr::Sort {
+                    expr,
+                    asc,
+                    nulls_first,
+                }) => match expr.as_ref() {
+                    Expr::Column(c)
+                        if self
+                            .original
+                            .schema()
+                            .field_with_unqualified_name(&c.name)
+                            .is_ok() =>
+                    {
+                        ordering.push((c.name.clone(), *asc, *nulls_first))
+                    }
+                    _ => return vec![],
+                },
+                _ => return vec![],
+            }
+        }
+        ordering
+    }
+
+    /// The input ordering columns aliased as `__order_{i}`, and the sort
+    /// expressions on them.
+    fn order_keys(&self) -> (Vec<Expr>, Vec<Expr>) {
+        self.input_ordering()
+            .into_iter()
+            .enumerate()
+            .map(|(i, (name, asc, nulls_first))| {
+                let key = format!("__order_{}", i);
+                (ident(name).alias(&key), ident(key).sort(asc, nulls_first))
+            })
+            .unzip()
+    }
+
+    /// `source` transformed, along with the input ordering as `__order_{i}`
+    /// columns and the sort expressions on them.
+    fn ordered(&self, source: DataFrame) -> anyhow::Result<(DataFrame, Vec<Expr>)> {
+        let mut expressions = source
+            .schema()
+            .fields()
+            .iter()
+            .map(|f| self.transform_field(f))
+            .collect::<Vec<_>>();
+        let (keys, order_by) = self.order_keys();
+        expressions.extend(keys);
+        Ok((source.select(expressions)?, order_by))
+    }
+
+    /// `first` (or `last`) of each (transformed) column of `source`, following
+    /// the input ordering.
+    fn first_last(&self, source: DataFrame, last: bool) -> anyhow::Result<DataFrame> {
+        let (df, order_by) = self.ordered(source)?;
+        let fun = if last {
+            aggregate_function::AggregateFunction::LastValue
+        } else {
+            aggregate_function::AggregateFunction::FirstValue
+        };
+        let aggregates = df
+            .schema()
+            .fields()
+            .iter()
+            .filter(|f| !f.name().starts_with("__order_"))
+            .map(|f| {
+                let expr = if f.data_type().is_numeric() {
+                    Expr::AggregateFunction(expr::AggregateFunction::new(
+                        fun.clone(),
+                        vec![ident(f.name())],
+                        false,
+                        None,
+                        Some(order_by.clone()),
+                        None,
+                    ))
+                } else {
+                    null_stat()
+                };
+                expr.alias(f.name())
+            })
+            .collect::<Vec<_>>();
+        Ok(df.aggregate(vec![], aggregates)?)
+    }
+
+    /// Number of rows smaller than the row before them, following the input
+    /// ordering (or the order rows are read in, without one).
+    fn monotonic_violations(&self, source: DataFrame) -> anyhow::Result<DataFrame> {
+        let (df, order_by) = self.ordered(source)?;
+        let fields = df
+            .schema()
+            .fields()
+            .iter()
+            .filter(|f| !f.name().starts_with("__order_"))
+            .cloned()
+            .collect::<Vec<_>>();
+        let previous = |name: &str| format!("__previous_{}", name);
+        let lags = fields
+            .iter()
+            .filter(|f| f.data_type().is_numeric())
+            .map(|f| {
+                Expr::WindowFunction(expr::WindowFunction::new(
+                    WindowFunctionDefinition::BuiltInWindowFunction(BuiltInWindowFunction::Lag),
+                    vec![ident(f.name())],
+                    vec![],
+                    order_by.clone(),
+                    WindowFrame::new(None),
+                    None,
+                ))
+                .alias(previous(f.name()))
+            })
+            .collect::<Vec<_>>();
+        let aggregates = fields
+            .iter()
+            .map(|f| {
+                let expr = if f.data_type().is_numeric() {
+                    // comparisons with null are null, so no violation
+                    sum(case(ident(f.name()).lt(ident(previous(f.name()))))
+                        .when(lit(true), lit(1i64))
+                        .otherwise(lit(0i64))
+                        .unwrap())
+                } else {
+                    null_stat()
+                };
+                expr.alias(f.name())
+            })
+            .collect::<Vec<_>>();
+        Ok(df.window(lags)?.aggregate(vec![], aggregates)?)
+    }
+
+    /// The flattened original, without the weight column.
+    fn unweighted_original(&self) -> anyhow::Result<DataFrame> {
+        unweighted(self.flattened()?)
+    }
+
+    fn cast_back(&self, df: DataFrame) -> anyhow::Result<DataFrame> {
+        // we need the describe column
+        let describe = Arc::new(Field::new("describe", DataType::Utf8, false));
+        let flattened = unweighted(self.flattened()?)?;
+        let mut fields = vec![&describe];
+        fields.extend(flattened.schema().fields().iter());
+        let mut expressions = fields
+            .into_iter()
+            .map(|field| {
+                let dt = field.data_type();
+                // stringified statistics (e.g. is_constant) can't be cast back
+                let stringified = matches!(
+                    df.schema()
+                        .field_with_unqualified_name(field.name())
+                        .map(|f| f.data_type()),
+                    Ok(DataType::Utf8)
+                );
+                let expr = match dt {
+                    _ if stringified => ident(field.name()),
+                    dt if dt.is_temporal() => cast(
+                        cast(ident(field.name()), self.temporal_stat_type()),
+                        dt.clone(),
+                    ),
+                    DataType::List(_) | DataType::LargeList(_) | DataType::Map(_, _) => {
+                        cast(ident(field.name()), DataType::Int32)
+                    }
+                    _ => ident(field.name()),
+                };
+                expr.alias(self.output_names.get(field.name()).unwrap_or(field.name()))
+            })
+            .collect::<Vec<_>>();
+        if self.dataset_summary {
+            expressions.extend(DATASET_SUMMARY_COLUMNS.iter().map(|name| ident(*name)));
+        }
+
+        // order rows by method definition order, then numerically by parameter
+        let mut methods = self.methods.iter().collect::<Vec<_>>();
+        methods.sort_by_key(|m| m.sort_key());
+        let mut order = case(col("describe"));
+        for (i, method) in methods.iter().enumerate() {
+            order.when(lit(method.to_string()), lit(i as u32));
+        }
+
+        // each method yields exactly one row; the limit also stops DataFusion from
+        // pushing filters on the result below the (global) aggregates, where a
+        // filtered-out method would still produce a row of empty statistics.
+        // The dataset summary row has no rank, so it sorts last.
+        let rows = self.methods.len() + usize::from(self.dataset_summary);
+        Ok(df
+            .select(expressions)?
+            .sort(vec![order.end()?.sort(true, false)])?
+            .limit(0, Some(rows))?)
+    }
+}
+
+impl fmt::Display for RangeCheckError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "cannot check range of column {}: {}",
+            self.column, self.reason
+        )
+    }
+}
+
+impl std::error::Error for RangeCheckError {}
+
+impl fmt::Display for SchemaValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "schema mismatch on column {}: {}",
+            self.column, self.reason
+        )
+    }
+}
+
+impl std::error::Error for SchemaValidationError {}
+
+impl fmt::Display for NullConstraintError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let columns = self
+            .columns
+            .iter()
+            .map(|(column, nulls)| format!("{} ({})", column, nulls))
+            .collect::<Vec<_>>();
+        write!(f, "null values in columns: {}", columns.join(", "))
+    }
+}
+
+impl std::error::Error for NullConstraintError {}
+
+impl fmt::Display for DescribeMethod {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DescribeMethod::Total => write!(f, "total"),
+            DescribeMethod::NullTotal => write!(f, "null_total"),
+            DescribeMethod::Mean => write!(f, "mean"),
+            DescribeMethod::Stddev => write!(f, "stddev"),
+            DescribeMethod::StddevSample => write!(f, "stddev_sample"),
+            DescribeMethod::StddevPop => write!(f, "stddev_pop"),
+            DescribeMethod::Min => write!(f, "min"),
+            DescribeMethod::Max => write!(f, "max"),
+            DescribeMethod::Median => write!(f, "median"),
+            DescribeMethod::Percentile(p) => write!(f, "percentile_{}", p),
+            // plain decimals (never an exponent) with a decimal point, so the label
+            // parses back, e.g. `percentile_0.00001` or `percentile_1.0`
+            DescribeMethod::PercentileF(p) if p.fract() == 0.0 => write!(f, "percentile_{:.1}", p),
+            DescribeMethod::PercentileF(p) => write!(f, "percentile_{}", p),
+            DescribeMethod::TopPercentile(p) => write!(f, "top_{}", p),
+            DescribeMethod::IsConstant => write!(f, "is_constant"),
+            DescribeMethod::WhitespacePaddedCount => write!(f, "whitespace_padded_count"),
+            DescribeMethod::Skewness => write!(f, "skewness"),
+            DescribeMethod::MaxDecimalPlaces => write!(f, "max_decimal_places"),
+            DescribeMethod::MinAbs => write!(f, "min_abs"),
+            DescribeMethod::MaxAbs => write!(f, "max_abs"),
+            DescribeMethod::Gini => write!(f, "gini"),
+            DescribeMethod::GiniImpurity => write!(f, "gini_impurity"),
+            DescribeMethod::EmptyCount => write!(f, "empty_count"),
+            DescribeMethod::NonEmptyCount => write!(f, "nonempty_count"),
+            DescribeMethod::First => write!(f, "first"),
+            DescribeMethod::Last => write!(f, "last"),
+            DescribeMethod::MonotonicViolations => write!(f, "monotonic_violations"),
+            DescribeMethod::HasMixedSign => write!(f, "has_mixed_sign"),
+            DescribeMethod::RangeUtilization => write!(f, "range_utilization"),
+            DescribeMethod::MinBytes => write!(f, "min_bytes"),
+            DescribeMethod::MaxBytes => write!(f, "max_bytes"),
+        }
+    }
+}
+
+impl FromStr for DescribeMethod {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let method = match s {
+            "total" => DescribeMethod::Total,
+            "null_total" => DescribeMethod::NullTotal,
+            "mean" => DescribeMethod::Mean,
+            "stddev" => DescribeMethod::Stddev,
+            "stddev_sample" => DescribeMethod::StddevSample,
+            "stddev_pop" => DescribeMethod::StddevPop,
+            "min" => DescribeMethod::Min,
+            "max" => DescribeMethod::Max,
+            "median" => DescribeMethod::Median,
+            "is_constant" => DescribeMethod::IsConstant,
+            "whitespace_padded_count" => DescribeMethod::WhitespacePaddedCount,
+            "skewness" => DescribeMethod::Skewness,
+            "max_decimal_places" => DescribeMethod::MaxDecimalPlaces,
+            "min_abs" => DescribeMethod::MinAbs,
+            "max_abs" => DescribeMethod::MaxAbs,
+            "gini" => DescribeMethod::Gini,
+            "gini_impurity" => DescribeMethod::GiniImpurity,
+            "empty_count" => DescribeMethod::EmptyCount,
+            "nonempty_count" => DescribeMethod::NonEmptyCount,
+            "first" => DescribeMethod::First,
+            "last" => DescribeMethod::Last,
+            "monotonic_violations" => DescribeMethod::MonotonicViolations,
+            "has_mixed_sign" => DescribeMethod::HasMixedSign,
+            "range_utilization" => DescribeMethod::RangeUtilization,
+            "min_bytes" => DescribeMethod::MinBytes,
+            "max_bytes" => DescribeMethod::MaxBytes,
+            _ => match (s.strip_prefix("percentile_"), s.strip_prefix("top_")) {
+                // whole percents, otherwise any fraction, e.g. `0.999` or `1e-5`
+                (Some(p), _) => match p.parse() {
+                    Ok(p) => DescribeMethod::Percentile(p),
+                    Err(_) => DescribeMethod::percentile_fraction(p.parse()?)?,
+                },
+                (_, Some(p)) => DescribeMethod::top_percentile(p.parse()?)?,
+                _ => anyhow::bail!("unknown describe method: {}", s),
+            },
+        };
+        Ok(method)
+    }
+}
+
+impl DescribeMethod {
+    /// Whether the statistic is nulled out by `with_min_count`.
+    fn needs_min_count(&self) -> bool {
+        matches!(
+            self,
+            DescribeMethod::Mean
+                | DescribeMethod::Stddev
+                | DescribeMethod::StddevSample
+                | DescribeMethod::StddevPop
+                | DescribeMethod::Median
+                | DescribeMethod::Percentile(_)
+                | DescribeMethod::PercentileF(_)
+                | DescribeMethod::TopPercentile(_)
+        )
+    }
+
+    /// `(category, parameter)` used to order describe rows: categories follow
+    /// the variant definition order, percentiles sort numerically by their
+    /// value in units of `1 / PERCENTILE_SORT_SCALE` percent.
+    pub fn sort_key(&self) -> (u32, u32) {
+        let percent = |p: f64| (p * PERCENTILE_SORT_SCALE).round() as u32;
+        match self {
+            DescribeMethod::Total => (0, 0),
+            DescribeMethod::NullTotal => (1, 0),
+            DescribeMethod::Mean => (2, 0),
+            DescribeMethod::Stddev => (3, 0),
+            DescribeMethod::StddevSample => (3, 1),
+            DescribeMethod::StddevPop => (3, 2),
+            DescribeMethod::Min => (4, 0),
+            DescribeMethod::Max => (5, 0),
+            DescribeMethod::Median => (6, 0),
+            DescribeMethod::Percentile(p) => (7, percent(*p as f64)),
+            DescribeMethod::PercentileF(p) => (7, percent(*p * 100.0)),
+            DescribeMethod::TopPercentile(p) => (7, percent(100u8.saturating_sub(*p) as f64)),
+            DescribeMethod::IsConstant => (8, 0),
+            DescribeMethod::WhitespacePaddedCount => (9, 0),
+            DescribeMethod::Skewness => (10, 0),
+            DescribeMethod::MaxDecimalPlaces => (11, 0),
+            DescribeMethod::MinAbs => (12, 0),
+            DescribeMethod::MaxAbs => (13, 0),
+            DescribeMethod::Gini => (14, 0),
+            DescribeMethod::GiniImpurity => (15, 0),
+            DescribeMethod::EmptyCount => (16, 0),
+            DescribeMethod::NonEmptyCount => (17, 0),
+            DescribeMethod::First => (18, 0),
+            DescribeMethod::Last => (19, 0),
+            DescribeMethod::MonotonicViolations => (20, 0),
+            DescribeMethod::HasMixedSign => (21, 0),
+            DescribeMethod::RangeUtilization => (22, 0),
+            DescribeMethod::MinBytes => (23, 0),
+            DescribeMethod::MaxBytes => (24, 0),
+        }
+    }
+
+    /// Create a [`DescribeMethod::PercentileF`], validating the fraction is within `[0, 1]`.
+    pub fn percentile_fraction(p: f64) -> anyhow::Result<Self> {
+        if !(0.0..=1.0).contains(&p) {
+            anyhow::bail!("percentile fraction must be within [0, 1], got {}", p);
+        }
+        Ok(DescribeMethod::PercentileF(p))
+    }
+
+    /// Create a [`DescribeMethod::TopPercentile`], validating `p` is within `[0, 100]`.
+    pub fn top_percentile(p: u8) -> anyhow::Result<Self> {
+        top_fraction(p)?;
+        Ok(DescribeMethod::TopPercentile(p))
+    }
+}
+
+/// The percentile fraction of `top_{p}`, e.g. 0.99 for `top_1`.
+fn top_fraction(p: u8) -> anyhow::Result<f64> {
+    100u8
+        .checked_sub(p)
+        .map(|q| q as f64 / 100.0)
+        .with_context(|| format!("top percentile must be within [0, 100], got {}", p))
+}
+
+macro_rules! describe_method {
+    ($name:ident, $method:ident) => {
+        fn $name(df: DataFrame) -> anyhow::Result<DataFrame> {
+            let fields = df.schema().fields().iter();
+            let ret = df.clone().aggregate(
+                vec![],
+                fields
+                    .filter(|f| f.data_type().is_numeric())
+                    .map(|f| $method(ident(f.name())).alias(f.name()))
+                    .collect::<Vec<_>>(),
+            )?;
+            Ok(ret)
+        }
+    };
+}
+
+describe_method!(total, count);
+describe_method!(mean, avg);
+describe_method!(std_div, stddev);
+describe_method!(std_div_pop, stddev_pop);
+describe_method!(minimum, min);
+describe_method!(maximum, max);
+describe_method!(med, median);
+describe_method!(minimum_abs, min_abs);
+describe_method!(maximum_abs, max_abs);
+
+fn min_abs(expr: Expr) -> Expr {
+    min(abs(expr))
+}
+
+fn max_abs(expr: Expr) -> Expr {
+    max(abs(expr))
+}
+
+macro_rules! describe_method_binary {
+    ($name:ident, $method:ident) => {
+        fn $name(df: DataFrame, col_a: &str, col_b: &str) -> anyhow::Result<DataFrame> {
+            let ret = df.aggregate(
+                vec![],
+                vec![$method(
+                    cast(ident(col_a), DataType::Float64),
+                    cast(ident(col_b), DataType::Float64),
+                )
+                .alias(stringify!($name))],
+            )?;
+            Ok(ret)
+        }
+    };
+}
+
+fn stddev_pop(expr: Expr) -> Expr {
+    Expr::AggregateFunction(expr::AggregateFunction::new(
+        aggregate_function::AggregateFunction::StddevPop,
+        vec![expr],
+        false,
+        None,
+        None,
+        None,
+    ))
+}
+
+fn covar(a: Expr, b: Expr) -> Expr {
+    covar_samp(a, b, false, None, None, None)
+}
+
+fn corr(a: Expr, b: Expr) -> Expr {
+    Expr::AggregateFunction(expr::AggregateFunction::new(
+        aggregate_function::AggregateFunction::Correlation,
+        vec![a, b],
+        false,
+        None,
+        None,
+        None,
+    ))
+}
+
+describe_method_binary!(covariance, covar);
+describe_method_binary!(correlation, corr);
+
+fn null_total(df: DataFrame) -> anyhow::Result<DataFrame> {
+    let fields = df.schema().fields().iter();
+    let ret = df.clone().aggregate(
+        vec![],
+        fields
+            .map(|f| {
+                sum(case(is_null(ident(f.name())))
+                    .when(lit(true), lit(1))
+                    .otherwise(lit(0))
+                    .unwrap())
+                .alias(f.name())
+            })
+            .collect::<Vec<_>>(),
+    )?;
+    Ok(ret)
+}
+
+fn is_constant(df: DataFrame) -> anyhow::Result<DataFrame> {
+    let fields = df.schema().fields().clone();
+    let ret = df
+        .aggregate(
+            vec![],
+            fields
+                .iter()
+                .map(|f| count_distinct(ident(f.name())).alias(f.name()))
+                .collect::<Vec<_>>(),
+        )?
+        .select(
+            fields
+                .iter()
+                .map(|f| {
+                    case(ident(f.name()).lt_eq(lit(1)))
+                        .when(lit(true), lit("true"))
+                        .otherwise(lit("false"))
+                        .unwrap()
+                        .alias(f.name())
+                })
+                .collect::<Vec<_>>(),
+        )?;
+    Ok(ret)
+}
+
+/// `"true"` for numeric columns with both negative and positive values,
+/// `"false"` otherwise and null without values. Other columns are null.
+fn has_mixed_sign(df: DataFrame) -> anyhow::Result<DataFrame> {
+    let fields = df.schema().fields().clone();
+    let numeric = fields
+        .iter()
+        .filter(|f| f.data_type().is_numeric())
+        .collect::<Vec<_>>();
+    let key = |name: &str, stat: &str| format!("{}__{}", name, stat);
+    // keeps the aggregate valid without numeric columns
+    let mut aggr_expr = vec![count(lit(1)).alias("__rows")];
+    for f in &numeric {
+        aggr_expr.push(min(ident(f.name())).alias(key(f.name(), "min")));
+        aggr_expr.push(max(ident(f.name())).alias(key(f.name(), "max")));
+    }
+    let ret = df.aggregate(vec![], aggr_expr)?.select(
+        fields
+            .iter()
+            .map(|f| {
+                let expr = if f.data_type().is_numeric() {
+                    let (lo, hi) = (ident(key(f.name(), "min")), ident(key(f.name(), "max")));
+                    when(lo.clone().is_null(), lit(ScalarValue::Utf8(None)))
+                        .when(lo.lt(lit(0)).and(hi.gt(lit(0))), lit("true"))
+                        .otherwise(lit("false"))
+                        .unwrap()
+                } else {
+                    lit(ScalarValue::Utf8(None))
+                };
+                expr.alias(f.name())
+            })
+            .collect::<Vec<_>>(),
+    )?;
+    Ok(ret)
+}
+
+/// The hex-encoded lexicographic min (or max, if `largest`) of each binary
+/// column, null for other columns.
+fn binary_extreme(df: DataFrame, largest: bool) -> anyhow::Result<DataFrame> {
+    let fields = df.schema().fields().clone();
+    let is_binary = |f: &Field| matches!(f.data_type(), DataType::Binary | DataType::LargeBinary);
+    // keeps the aggregate valid without binary columns
+    let mut aggr_expr = vec![count(lit(1)).alias("__rows")];
+    for f in fields.iter().filter(|f| is_binary(f)) {
+        let expr = if largest {
+            max(ident(f.name()))
+        } else {
+            min(ident(f.name()))
+        };
+        aggr_expr.push(expr.alias(f.name()));
+    }
+    let ret = df.aggregate(vec![], aggr_expr)?.select(
+        fields
+            .iter()
+            .map(|f| {
+                let expr = if is_binary(f) {
+                    cast(encode(ident(f.name()), lit("hex")), DataType::Utf8)
+                } else {
+                    lit(ScalarValue::Utf8(None))
+                };
+                expr.alias(f.name())
+            })
+            .collect::<Vec<_>>(),
+    )?;
+    Ok(ret)
+}
+
+/// `max - min` of each integer column divided by the width of its type's range,
+/// null for other columns.
+fn range_utilization(df: DataFrame) -> anyhow::Result<DataFrame> {
+    let fields = df.schema().fields().clone();
+    let bits = |dt: &DataType| match dt {
+        DataType::Int8 | DataType::UInt8 => Some(8),
+        DataType::Int16 | DataType::UInt16 => Some(16),
+        DataType::Int32 | DataType::UInt32 => Some(32),
+        DataType::Int64 | DataType::UInt64 => Some(64),
+        _ => None,
+    };
+    let key = |name: &str, stat: &str| format!("{}__{}", name, stat);
+    // keeps the aggregate valid without integer columns
+    let mut aggr_expr = vec![count(lit(1)).alias("__rows")];
+    for f in fields.iter().filter(|f| bits(f.data_type()).is_some()) {
+        let x = cast(ident(f.name()), DataType::Float64);
+        aggr_expr.push(min(x.clone()).alias(key(f.name(), "min")));
+        aggr_expr.push(max(x).alias(key(f.name(), "max")));
+    }
+    let ret = df.aggregate(vec![], aggr_expr)?.select(
+        fields
+            .iter()
+            .map(|f| {
+                let expr = match bits(f.data_type()) {
+                    Some(bits) => {
+                        (ident(key(f.name(), "max")) - ident(key(f.name(), "min")))
+                            / lit(2f64.powi(bits) - 1.0)
+                    }
+                    None => lit(ScalarValue::Float64(None)),
+                };
+                expr.alias(f.name())
+            })
+            .collect::<Vec<_>>(),
+    )?;
+    Ok(ret)
+}
+
+/// Number of entries in each map of a `Map` column, analogous to `array_length`.
+fn map_length(dt: &DataType) -> ScalarUDF {
+    create_udf(
+        "map_length",
+        vec![dt.clone()],
+        Arc::new(DataType::Int32),
+        Volatility::Immutable,
+        Arc::new(|args: &[ColumnarValue]| {
+            let array = args[0].clone().into_array(1)?;
+            let map = array
+                .as_any()
+                .downcast_ref::<MapArray>()
+                .expect("map_length is only registered for Map columns");
+            let offsets = map.value_offsets();
+            let lengths = (0..map.len())
+                .map(|i| (!map.is_null(i)).then(|| offsets[i + 1] - offsets[i]))
+                .collect::<Int32Array>();
+            Ok(ColumnarValue::Array(Arc::new(lengths)))
+        }),
+    )
+}
+
+/// Number of bytes of each value of a `Binary` or `LargeBinary` column, as Float64.
+/// DataFusion's `octet_length` only takes strings, and casting to one would
+/// reject (or mangle) non-UTF-8 data.
+fn byte_length(dt: &DataType) -> ScalarUDF {
+    create_udf(
+        "byte_length",
+        vec![dt.clone()],
+        Arc::new(DataType::Float64),
+        Volatility::Immutable,
+        Arc::new(|args: &[ColumnarValue]| {
+            let array = args[0].clone().into_array(1)?;
+            let lengths = arrow::compute::kernels::length::length(&array)?;
+            Ok(ColumnarValue::Array(cast_array(
+                &lengths,
+                &DataType::Float64,
+            )?))
+        }),
+    )
+}
+
+/// Population skewness, `m3 / m2^1.5` from the second and third central
+/// moments. The mean is subtracted before raising to powers, as raw moments
+/// lose all precision for values with a large offset.
+fn skewness(df: DataFrame) -> anyhow::Result<DataFrame> {
+    let fields = df
+        .schema()
+        .fields()
+        .iter()
+        .filter(|f| f.data_type().is_numeric())
+        .cloned()
+        .collect::<Vec<_>>();
+    let moment = |f: &Field, n: usize| format!("{}__m{}", f.name(), n);
+    let value = |f: &Field| cast(ident(f.name()), DataType::Float64);
+
+    let means = df.clone().aggregate(
+        vec![],
+        fields
+            .iter()
+            .map(|f| avg(value(f)).alias(moment(f, 1)))
+            .collect::<Vec<_>>(),
+    )?;
+    let ret = df
+        .join_on(means, JoinType::Inner, vec![])?
+        .aggregate(
+            vec![],
+            fields
+                .iter()
+                .flat_map(|f| {
+                    let d = value(f) - ident(moment(f, 1));
+                    [
+                        avg(d.clone() * d.clone()).alias(moment(f, 2)),
+                        avg(d.clone() * d.clone() * d).alias(moment(f, 3)),
+                    ]
+                })
+                .collect::<Vec<_>>(),
+        )?
+        .select(
+            fields
+                .iter()
+                .map(|f| {
+                    let (m2, m3) = (ident(moment(f, 2)), ident(moment(f, 3)));
+                    (m3 / (m2.clone() * sqrt(m2))).alias(f.name())
+                })
+                .collect::<Vec<_>>(),
+        )?;
+    Ok(ret)
+}
+
+/// Gini coefficient from the cumulative sums `S_i` of the ascending values:
+/// `(n + 1 - 2 * sum(S_i) / S_n) / n`. Null for columns with negative values
+/// or a non-positive total.
+fn gini(df: DataFrame) -> anyhow::Result<DataFrame> {
+    let mut ret: Option<DataFrame> = None;
+    for f in df
+        .schema()
+        .fields()
+        .iter()
+        .filter(|f| f.data_type().is_numeric())
+    {
+        let x = ident("x");
+        let cumulative = Expr::WindowFunction(expr::WindowFunction::new(
+            WindowFunctionDefinition::AggregateFunction(aggregate_function::AggregateFunction::Sum),
+            vec![x.clone()],
+            vec![],
+            vec![x.clone().sort(true, false)],
+            WindowFrame::new(Some(true)),
+            None,
+        ));
+        let (total, n) = (ident("total"), cast(ident("n"), DataType::Float64));
+        let coefficient =
+            (n.clone() + lit(1.0) - lit(2.0) * ident("cumulative") / total.clone()) / n;
+        let stat = df
+            .clone()
+            .select(vec![cast(ident(f.name()), DataType::Float64).alias("x")])?
+            .filter(x.clone().is_not_null())?
+            .window(vec![cumulative.alias("s")])?
+            .aggregate(
+                vec![],
+                vec![
+                    sum(ident("s")).alias("cumulative"),
+                    sum(x.clone()).alias("total"),
+                    count(x.clone()).alias("n"),
+                    min(x).alias("min"),
+                ],
+            )?
+            .select(vec![case(
+                ident("min").lt(lit(0.0)).or(total.lt_eq(lit(0.0))),
+            )
+            .when(lit(true), lit(ScalarValue::Float64(None)))
+            .otherwise(coefficient)?
+            .alias(f.name())])?;
+        ret = Some(match ret {
+            Some(acc) => acc.join_on(stat, JoinType::Inner, vec![])?,
+            None => stat,
+        });
+    }
+    ret.ok_or_else(|| anyhow::anyhow!("no numeric columns for gini"))
+}
+
+/// Median of each numeric column, see [`MedianStrategy`]. `Lower` and `Higher`
+/// pick the value ranked `(n + 1) / 2` or `n / 2 + 1` (integer division).
+fn median_with(df: DataFrame, strategy: MedianStrategy) -> anyhow::Result<DataFrame> {
+    let numeric = df
+        .schema()
+        .fields()
+        .iter()
+        .filter(|f| f.data_type().is_numeric())
+        .cloned()
+        .collect::<Vec<_>>();
+    if strategy == MedianStrategy::Interpolate {
+        return Ok(df.aggregate(
+            vec![],
+            numeric
+                .iter()
+                .map(|f| median(cast(ident(f.name()), DataType::Float64)).alias(f.name()))
+                .collect(),
+        )?);
+    }
+
+    let mut ret: Option<DataFrame> = None;
+    for f in numeric {
+        let x = ident("x");
+        let row_number = Expr::WindowFunction(expr::WindowFunction::new(
+            WindowFunctionDefinition::BuiltInWindowFunction(BuiltInWindowFunction::RowNumber),
+            vec![],
+            vec![],
+            vec![x.clone().sort(true, false)],
+            WindowFrame::new(Some(true)),
+            None,
+        ));
+        let n = ident("n");
+        let rank = match strategy {
+            MedianStrategy::Lower => (n + lit(1i64)) / lit(2i64),
+            _ => n / lit(2i64) + lit(1i64),
+        };
+        let values = df
+            .clone()
+            .select(vec![ident(f.name()).alias("x")])?
+            .filter(x.clone().is_not_null())?;
+        let total = values
+            .clone()
+            .aggregate(vec![], vec![count(x.clone()).alias("n")])?;
+        let stat = values
+            .window(vec![row_number.alias("rn")])?
+            .join_on(total, JoinType::Inner, vec![])?
+            .filter(cast(ident("rn"), DataType::Int64).eq(rank))?
+            .aggregate(vec![], vec![max(x).alias(f.name())])?;
+        ret = Some(match ret {
+            Some(acc) => acc.join_on(stat, JoinType::Inner, vec![])?,
+            None => stat,
+        });
+    }
+    ret.ok_or_else(|| anyhow::anyhow!("no numeric columns for median"))
+}
+
+/// Gini impurity from the count `c_i` of each distinct non-null value:
+/// `1 - sum(c_i^2) / n^2`.
+fn gini_impurity(df: DataFrame) -> anyhow::Result<DataFrame> {
+    let mut ret: Option<DataFrame> = None;
+    for f in df
+        .schema()
+        .fields()
+        .iter()
+        .filter(|f| f.data_type().is_numeric())
+    {
+        let c = cast(ident("c"), DataType::Float64);
+        let n = ident("n");
+        let stat = df
+            .clone()
+            .select(vec![ident(f.name()).alias("x")])?
+            .filter(ident("x").is_not_null())?
+            .aggregate(vec![ident("x")], vec![count(lit(1)).alias("c")])?
+            .aggregate(
+                vec![],
+                vec![
+                    sum(c.clone() * c.clone()).alias("squares"),
+                    sum(c).alias("n"),
+                ],
+            )?
+            .select(vec![
+                (lit(1.0) - ident("squares") / (n.clone() * n)).alias(f.name())
+            ])?;
+        ret = Some(match ret {
+            Some(acc) => acc.join_on(stat, JoinType::Inner, vec![])?,
+            None => stat,
+        });
+    }
+    ret.ok_or_else(|| anyhow::anyhow!("no numeric columns for gini_impurity"))
+}
+
+/// Maximum number of fractional digits used by float columns. This is approximate:
+/// it counts the digits after the `.` of each value's shortest string form, ignoring
+/// trailing zeros, so values rendered in scientific notation are not accounted for.
+fn max_decimal_places(df: DataFrame) -> anyhow::Result<DataFrame> {
+    let fields = df.schema().fields().iter();
+    let ret = df.clone().aggregate(
+        vec![],
+        fields
+            .map(|f| {
+                let expr = match f.data_type() {
+                    DataType::Float16 | DataType::Float32 | DataType::Float64 => {
+                        let fraction =
+                            split_part(cast(ident(f.name()), DataType::Utf8), lit("."), lit(2));
+                        max(length(rtrim(vec![fraction, lit("0")])))
+                    }
+                    _ => null_stat(),
+                };
+                expr.alias(f.name())
+            })
+            .collect::<Vec<_>>(),
+    )?;
+    Ok(ret)
+}
+
+/// Collect a single-row DataFrame into a map of column name to value as f64.
+async fn collect_single_row(df: DataFrame) -> anyhow::Result<HashMap<String, Option<f64>>> {
+    let batches = df.collect().await?;
+    let batch = batches
+        .iter()
+        .find(|b| b.num_rows() > 0)
+        .ok_or_else(|| anyhow::anyhow!("expected a single row"))?;
+
+    let mut ret = HashMap::new();
+    for (field, column) in batch.schema().fields().iter().zip(batch.columns()) {
+        let values = cast_array(column, &DataType::Float64)?;
+        let values = values
+            .as_any()
+            .downcast_ref::<Float64Array>()
+            .expect("cast to Float64 must yield a Float64Array");
+        ret.insert(field.name().to_string(), values.iter().next().flatten());
+    }
+    Ok(ret)
+}
+
+/// Push `expr` aliased as `name`, or, for a struct, each of its child fields
+/// (recursively) aliased as `name.child`.
+fn flatten_field(expr: Expr, name: &str, dt: &DataType, out: &mut Vec<Expr>) {
+    match dt {
+        DataType::Struct(children) => {
+            for child in children {
+                flatten_field(
+                    get_field(expr.clone(), lit(child.name().as_str())),
+                    &format!("{}.{}", name, child.name()),
+                    child.data_type(),
+                    out,
+                );
+            }
+        }
+        _ => out.push(expr.alias(name)),
+    }
+}
+
+/// Sum of the `elapsed_compute` metric (in nanoseconds) over `plan` and its children.
+fn elapsed_compute(plan: &dyn ExecutionPlan) -> usize {
+    let own = plan
+        .metrics()
+        .and_then(|m| m.elapsed_compute())
+        .unwrap_or_default();
+    own + plan
+        .children()
+        .iter()
+        .map(|child| elapsed_compute(child.as_ref()))
+        .sum::<usize>()
+}
+
+/// Registers an object store for the scheme and bucket of `url` on `ctx`, unless
+/// it has one. Fails for schemes other than `REMOTE_SCHEMES`.
+fn register_object_store(ctx: &SessionContext, url: &str) -> anyhow::Result<()> {
+    let table_url = ListingTableUrl::parse(url)?;
+    if !REMOTE_SCHEMES.contains(&table_url.scheme()) {
+        anyhow::bail!("unsupported url scheme: {}", table_url.scheme());
+    }
+    let store_url = table_url.object_store();
+    if ctx.runtime_env().object_store(&store_url).is_ok() {
+        return Ok(());
+    }
+    let context = || format!("can't create an object store for {}", store_url.as_str());
+    let store: Arc<dyn ObjectStore> = match table_url.scheme() {
+        "s3" => Arc::new(
+            AmazonS3Builder::from_env()
+                .with_url(url)
+                .build()
+                .with_context(context)?,
+        ),
+        "gs" => Arc::new(
+            GoogleCloudStorageBuilder::from_env()
+                .with_url(url)
+                .build()
+                .with_context(context)?,
+        ),
+        // az, the last of REMOTE_SCHEMES
+        _ => Arc::new(
+            MicrosoftAzureBuilder::from_env()
+                .with_url(url)
+                .build()
+                .with_context(context)?,
+        ),
+    };
+    ctx.runtime_env()
+        .register_object_store(store_url.as_ref(), store);
+    Ok(())
+}
+
+/// The result of `df` from `cache`, collecting and storing it on a miss.
+async fn cached(cache: &DescribeCache, df: DataFrame) -> anyhow::Result<DataFrame> {
+    let mut sources = Vec::new();
+    scan_sources(df.logical_plan(), &mut sources);
+    let fingerprint = plan_fingerprint(df.logical_plan(), &sources);
+    let schema = Arc::new(df.schema().as_arrow().clone());
+    let hit = cache
+        .lock()
+        .unwrap()
+        .get(&fingerprint)
+        .filter(|entry| entry.scans(&sources))
+        .map(|entry| entry.batches.clone());
+    let batches = match hit {
+        Some(batches) => batches,
+        None => {
+            let batches = df.collect().await?;
+            let entry = CachedDescribe {
+                sources,
+                batches: batches.clone(),
+            };
+            cache.lock().unwrap().insert(fingerprint, entry);
+            batches
+        }
+    };
+    Ok(SessionContext::new().read_batch(concat_batches(&schema, &batches)?)?)
+}
+
+/// Appends the tables scanned by `plan` and its inputs to `sources`.
+fn scan_sources(plan: &LogicalPlan, sources: &mut Vec<Arc<dyn TableSource>>) {
+    if let LogicalPlan::TableScan(scan) = plan {
+        sources.push(scan.source.clone());
+    }
+    for input in plan.inputs() {
+        scan_sources(input, sources);
+    }
+}
+
+/// Fingerprint of `plan`: its (debug) text plus the addresses of the tables it
+/// scans, `sources`, as the text doesn't tell e.g. two in-memory tables apart.
+fn plan_fingerprint(plan: &LogicalPlan, sources: &[Arc<dyn TableSource>]) -> PlanFingerprint {
+    let mut hasher = Fnv1a::default();
+    hasher.write(format!("{:?}", plan).as_bytes());
+    for source in sources {
+        let address = Arc::as_ptr(source) as *const () as usize;
+        hasher.write(&address.to_le_bytes());
+    }
+    hasher.0
+}
+
+/// Appends `plan` and its inputs as DOT nodes and edges, numbering nodes from
+/// `next_id`. Returns the id of the node of `plan`.
+fn plan_dot(plan: &dyn ExecutionPlan, next_id: &mut usize, dot: &mut String) -> usize {
+    let id = *next_id;
+    *next_id += 1;
+    let label = displayable(plan).one_line().to_string();
+    dot.push_str(&format!(
+        "    n{} [label=\"{}\"];\n",
+        id,
+        label.trim().replace('\\', "\\\\").replace('"', "\\\"")
+    ));
+    for child in plan.children() {
+        let child_id = plan_dot(child.as_ref(), next_id, dot);
+        dot.push_str(&format!("    n{} -> n{};\n", child_id, id));
+    }
+    id
+}
+
+/// Materialize `df` and re-emit its rows in batches of at most `size` rows.
+async fn rebatch(df: DataFrame, size: usize) -> anyhow::Result<DataFrame> {
+    let batches = df.collect().await?;
+    let Some(first) = batches.first() else {
+        return Ok(SessionContext::new().read_batches(batches)?);
+    };
+    let batch = concat_batches(&first.schema(), &batches)?;
+    Ok(SessionContext::new().read_batches(slice_rows(&batch, size))?)
+}
+
+/// `batch` split into consecutive slices of `size` rows, the last one possibly shorter.
+fn slice_rows(batch: &RecordBatch, size: usize) -> impl Iterator<Item = RecordBatch> + '_ {
+    (0..batch.num_rows())
+        .step_by(size)
+        .map(move |offset| batch.slice(offset, size.min(batch.num_rows() - offset)))
+}
+
+/// Pivots a long `describe` output, one row per statistic labeled in the
+/// `describe` column and one column per described column, into the wide layout:
+/// one row per described column (named in `column`) and one column per statistic.
+/// Values keep their type if all columns share it, otherwise they are stringified.
+/// The `with_dataset_summary` row and columns are left out, as they don't
+/// describe a column.
+pub async fn transpose(df: DataFrame) -> anyhow::Result<DataFrame> {
+    let schema = Arc::new(df.schema().as_arrow().clone());
+    if df.schema().field_with_unqualified_name("describe").is_err() {
+        anyhow::bail!("no describe column to transpose on");
+    }
+    let batch = concat_batches(&schema, &df.collect().await?)?;
+
+    let value_fields = schema
+        .fields()
+        .iter()
+        .filter(|f| f.name() != "describe" && !DATASET_SUMMARY_COLUMNS.contains(&f.name().as_str()))
+        .collect::<Vec<_>>();
+    let value_type = match value_fields.first() {
+        Some(first)
+            if value_fields
+                .iter()
+                .all(|f| f.data_type() == first.data_type()) =>
+        {
+            first.data_type().clone()
+        }
+        _ => DataType::Utf8,
+    };
+    let labels = batch.column_by_name("describe").expect("describe column");
+
+    let mut fields = vec![Field::new("column", DataType::Utf8, false)];
+    let mut columns = vec![Arc::new(StringArray::from(
+        value_fields
+            .iter()
+            .map(|f| f.name().as_str())
+            .collect::<Vec<_>>(),
+    )) as ArrayRef];
+    for i in 0..batch.num_rows() {
+        let label = stringify(labels, i)?.unwrap_or_default();
+        if label == DATASET_SUMMARY_LABEL {
+            continue;
+        }
+        let values = value_fields
+            .iter()
+            .map(|f| {
+                let array = batch.column_by_name(f.name()).expect("value column");
+                cast_array(&array.slice(i, 1), &value_type)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let values = values.iter().map(|a| a.as_ref()).collect::<Vec<_>>();
+        fields.push(Field::new(label, value_type.clone(), true));
+        columns.push(concat(&values)?);
+    }
+    let batch = RecordBatch::try_new(Arc::new(Schema::new(fields)), columns)?;
+    Ok(SessionContext::new().read_batch(batch)?)
+}
+
+/// Inverse CDF of the standard normal distribution (Acklam's rational
+/// approximation, relative error below 1.2e-9).
+fn normal_quantile(p: f64) -> f64 {
+    const A: [f64; 6] = [
+        -3.969683028665376e1,
+        2.209460984245205e2,
+        -2.759285104469687e2,
+        1.38357751867269e2,
+        -3.066479806614716e1,
+        2.506628277459239,
+    ];
+    const B: [f64; 5] = [
+        -5.447609879822406e1,
+        1.615858368580409e2,
+        -1.556989798598866e2,
+        6.680131188771972e1,
+        -1.328068155288572e1,
+    ];
+    const C: [f64; 6] = [
+        -7.784894002430293e-3,
+        -3.223964580411365e-1,
+        -2.400758277161838,
+        -2.549732539343734,
+        4.374664141464968,
+        2.938163982698783,
+    ];
+    const D: [f64; 4] = [
+        7.784695709041462e-3,
+        3.224671290700398e-1,
+        2.445134137142996,
+        3.754408661907416,
+    ];
+    const P_LOW: f64 = 0.02425;
+
+    let tail = |q: f64| {
+        (((((C[0] * q + C[1]) * q + C[2]) * q + C[3]) * q + C[4]) * q + C[5])
+            / ((((D[0] * q + D[1]) * q + D[2]) * q + D[3]) * q + 1.0)
+    };
+    if p < P_LOW {
+        tail((-2.0 * p.ln()).sqrt())
+    } else if p > 1.0 - P_LOW {
+        -tail((-2.0 * (1.0 - p).ln()).sqrt())
+    } else {
+        let q = p - 0.5;
+        let r = q * q;
+        (((((A[0] * r + A[1]) * r + A[2]) * r + A[3]) * r + A[4]) * r + A[5]) * q
+            / (((((B[0] * r + B[1]) * r + B[2]) * r + B[3]) * r + B[4]) * r + 1.0)
+    }
+}
+
+/// CDF of the standard normal distribution (Abramowitz and Stegun 26.2.17,
+/// absolute error below 7.5e-8).
+fn normal_cdf(x: f64) -> f64 {
+    const B: [f64; 5] = [
+        0.319381530,
+        -0.356563782,
+        1.781477937,
+        -1.821255978,
+        1.330274429,
+    ];
+    let t = 1.0 / (1.0 + 0.2316419 * x.abs());
+    let density = (-x * x / 2.0).exp() / (2.0 * std::f64::consts::PI).sqrt();
+    let tail = density * t * (B[0] + t * (B[1] + t * (B[2] + t * (B[3] + t * B[4]))));
+    if x >= 0.0 {
+        1.0 - tail
+    } else {
+        tail
+    }
+}
+
+/// The `p` quantile (`p` within `(0, 1)`) of Student's t distribution with `df > 0`
+/// degrees of freedom, found by bisecting [`t_cdf`] to about 1e-9 (relative).
+fn t_quantile(p: f64, df: f64) -> f64 {
+    if p < 0.5 {
+        return -t_quantile(1.0 - p, df);
+    }
+    let mut hi = 1.0;
+    while t_cdf(hi, df) < p && hi < 1e12 {
+        hi *= 2.0;
+    }
+    let mut lo = 0.0;
+    while hi - lo > 1e-10 * hi {
+        let mid = (lo + hi) / 2.0;
+        if t_cdf(mid, df) < p {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+    (lo + hi) / 2.0
+}
+
+/// CDF of Student's t distribution with `df` degrees of freedom, through the
+/// regularized incomplete beta function.
+fn t_cdf(t: f64, df: f64) -> f64 {
+    let tail = 0.5 * incomplete_beta(df / (df + t * t), df / 2.0, 0.5);
+    if t > 0.0 {
+        1.0 - tail
+    } else {
+        tail
+    }
+}
+
+/// Regularized incomplete beta function `I_x(a, b)`, evaluated with the
+/// continued fraction of Numerical Recipes' `betai` (modified Lentz's method).
+fn incomplete_beta(x: f64, a: f64, b: f64) -> f64 {
+    if x <= 0.0 {
+        return 0.0;
+    }
+    if x >= 1.0 {
+        return 1.0;
+    }
+    let front =
+        (ln_gamma(a + b) - ln_gamma(a) - ln_gamma(b) + a * x.ln() + b * (1.0 - x).ln()).exp();
+    // the continued fraction converges quickly below this point, use symmetry above
+    if x < (a + 1.0) / (a + b + 2.0) {
+        front * beta_continued_fraction(x, a, b) / a
+    } else {
+        1.0 - front * beta_continued_fraction(1.0 - x, b, a) / b
+    }
+}
+
+/// The continued fraction of [`incomplete_beta`], Numerical Recipes' `betacf`.
+fn beta_continued_fraction(x: f64, a: f64, b: f64) -> f64 {
+    const TINY: f64 = 1e-300;
+    let nonzero = |v: f64| if v.abs() < TINY { TINY } else { v };
+    let (qab, qap, qam) = (a + b, a + 1.0, a - 1.0);
+    let mut c = 1.0;
+    let mut d = 1.0 / nonzero(1.0 - qab * x / qap);
+    let mut h = d;
+    for m in 1..=300 {
+        let m = m as f64;
+        let even = m * (b - m) * x / ((qam + 2.0 * m) * (a + 2.0 * m));
+        d = 1.0 / nonzero(1.0 + even * d);
+        c = nonzero(1.0 + even / c);
+        h *= d * c;
+        let odd = -(a + m) * (qab + m) * x / ((a + 2.0 * m) * (qap + 2.0 * m));
+        d = 1.0 / nonzero(1.0 + odd * d);
+        c = nonzero(1.0 + odd / c);
+        let delta = d * c;
+        h *= delta;
+        if (delta - 1.0).abs() < 1e-15 {
+            break;
+        }
+    }
+    h
+}
+
+/// Natural logarithm of the gamma function for `x >= 0.5` (Lanczos
+/// approximation with g = 7, relative error around 1e-15).
+fn ln_gamma(x: f64) -> f64 {
+    const COEFFICIENTS: [f64; 9] = [
+        0.999_999_999_999_809_9,
+        676.520_368_121_885_1,
+        -1_259.139_216_722_402_8,
+        771.323_428_777_653_1,
+        -176.615_029_162_140_6,
+        12.507_343_278_686_905,
+        -0.138_571_095_265_720_12,
+        9.984_369_578_019_572e-6,
+        1.505_632_735_149_311_6e-7,
+    ];
+    let x = x - 1.0;
+    let sum = COEFFICIENTS[1..]
+        .iter()
+        .enumerate()
+        .fold(COEFFICIENTS[0], |acc, (i, c)| {
+            acc + c / (x + i as f64 + 1.0)
+        });
+    let t = x + 7.5;
+    0.5 * (2.0 * std::f64::consts::PI).ln() + (x + 0.5) * t.ln() - t + sum.ln()
+}
+
+/// 64-bit FNV-1a, used where the hash must not change between Rust releases
+/// (unlike `DefaultHasher`).
+struct Fnv1a(u64);
+
+impl Default for Fnv1a {
+    fn default() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+impl Fnv1a {
+    fn write(&mut self, bytes: &[u8]) {
+        // a separator, so ("ab", "c") and ("a", "bc") hash differently
+        for byte in bytes.iter().chain(&[0xff]) {
+            self.0 ^= *byte as u64;
+            self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+}
+
+/// The value at `i` of `array` as a string, `None` if null.
+fn stringify(array: &ArrayRef, i: usize) -> anyhow::Result<Option<String>> {
+    if array.is_null(i) {
+        return Ok(None);
+    }
+    Ok(Some(array_value_to_string(array, i)?))
+}
+
+/// The value at `i` as JSON: integers and floats as numbers, anything else
+/// stringified.
+fn json_value(array: &ArrayRef, i: usize) -> anyhow::Result<Value> {
+    if array.is_null(i) {
+        return Ok(Value::Null);
+    }
+    let dt = array.data_type();
+    let value = if dt.is_integer() {
+        let values = cast_array(&array.slice(i, 1), &DataType::Int64)?;
+        json!(values
+            .as_any()
+            .downcast_ref::<Int64Array>()
+            .unwrap()
+            .value(0))
+    } else if dt.is_numeric() {
+        let values = cast_array(&array.slice(i, 1), &DataType::Float64)?;
+        json!(values
+            .as_any()
+            .downcast_ref::<Float64Array>()
+            .unwrap()
+            .value(0))
+    } else {
+        Value::String(array_value_to_string(array, i)?)
+    };
+    Ok(value)
+}
+
+fn compatible_types(actual: &DataType, expected: &DataType) -> bool {
+    let is_string = |dt: &DataType| matches!(dt, DataType::Utf8 | DataType::LargeUtf8);
+    actual == expected
+        || (actual.is_numeric() && expected.is_numeric())
+        || (is_string(actual) && is_string(expected))
+}
+
+/// `method` on `f` keeping the column type where it makes sense, see
+/// [`DataFrameDescriber::describe_struct`].
+fn typed_stat(method: &DescribeMethod, f: &Field, udaf: Option<&AggregateUDF>) -> Option<Expr> {
+    let x = ident(f.name());
+    let numeric = f.data_type().is_numeric();
+    let percentile = |p: f64| match (numeric, udaf) {
+        (false, _) => null_stat(),
+        (true, Some(udaf)) => udaf.call(vec![x.clone(), lit(p)]),
+        (true, None) => approx_percentile_cont(x.clone(), lit(p)),
+    };
+    let expr = match method {
+        DescribeMethod::Total => count(x),
+        DescribeMethod::NullTotal => sum(case(is_null(x))
+            .when(lit(true), lit(1i64))
+            .otherwise(lit(0i64))
+            .unwrap()),
+        DescribeMethod::Mean if numeric => avg(cast(x, DataType::Float64)),
+        DescribeMethod::Stddev | DescribeMethod::StddevSample if numeric => {
+            stddev(cast(x, DataType::Float64))
+        }
+        DescribeMethod::StddevPop if numeric => stddev_pop(cast(x, DataType::Float64)),
+        DescribeMethod::Mean
+        | DescribeMethod::Stddev
+        | DescribeMethod::StddevSample
+        | DescribeMethod::StddevPop => null_stat(),
+        DescribeMethod::Min => min(x),
+        DescribeMethod::Max => max(x),
+        DescribeMethod::Median if numeric => median(x),
+        DescribeMethod::Median => null_stat(),
+        DescribeMethod::Percentile(p) => percentile(*p as f64 / 100.0),
+        DescribeMethod::PercentileF(p) => percentile(*p),
+        DescribeMethod::TopPercentile(p) => percentile(top_fraction(*p).ok()?),
+        _ => return None,
+    };
+    Some(expr)
+}
+
+/// Bin frequencies of the histogram `counts` with one extra count per bin.
+fn laplace_smoothed(counts: &[i64]) -> Vec<f64> {
+    let total = counts.iter().sum::<i64>() as f64 + counts.len() as f64;
+    counts.iter().map(|n| (*n as f64 + 1.0) / total).collect()
+}
+
+/// Number of histogram bins for `n` values by Sturges' rule.
+fn sturges_bins(n: f64) -> usize {
+    (n.log2() + 1.0).ceil().max(1.0) as usize
+}
+
+/// Number of histogram bins that are above the average count and not lower
+/// than their neighbours (the first bin of a plateau counts once).
+fn count_modes(counts: &[i64]) -> usize {
+    let average = counts.iter().sum::<i64>() as f64 / counts.len() as f64;
+    (0..counts.len())
+        .filter(|&i| {
+            let c = counts[i];
+            (c as f64) > average
+                && (i == 0 || c > counts[i - 1])
+                && (i + 1 == counts.len() || c >= counts[i + 1])
+        })
+        .count()
+}
+
+/// Null out the statistics in `stat_df` of the columns of `df` with fewer than
+/// `n` non-null values.
+fn guard_min_count(stat_df: DataFrame, df: DataFrame, n: usize) -> anyhow::Result<DataFrame> {
+    let key = |name: &str| format!("{}__count", name);
+    let counts = df.clone().aggregate(
+        vec![],
+        df.schema()
+            .fields()
+            .iter()
+            .map(|f| count(ident(f.name())).alias(key(f.name())))
+            .collect(),
+    )?;
+    let select_expr = stat_df
+        .schema()
+        .fields()
+        .iter()
+        .map(|f| {
+            case(ident(key(f.name())).gt_eq(lit(n as i64)))
+                .when(lit(true), ident(f.name()))
+                .end()
+                .unwrap()
+                .alias(f.name())
+        })
+        .collect::<Vec<_>>();
+    Ok(stat_df
+        .join_on(counts, JoinType::Inner, vec![])?
+        .select(select_expr)?)
+}
+
+/// The statistics of `col` in a `describe_map` result, failing if it wasn't
+/// described (e.g. it is the weight column).
+fn described<'a>(
+    stats: &'a HashMap<String, HashMap<String, Option<f64>>>,
+    col: &str,
+) -> anyhow::Result<&'a HashMap<String, Option<f64>>> {
+    stats
+        .get(col)
+        .with_context(|| format!("column {} is not described", col))
+}
+
+/// Drop the `WEIGHT_COLUMN` from `df`, if any.
+fn unweighted(df: DataFrame) -> anyhow::Result<DataFrame> {
+    let columns = df
+        .schema()
+        .fields()
+        .iter()
+        .filter(|f| f.name() != WEIGHT_COLUMN)
+        .map(|f| ident(f.name()))
+        .collect::<Vec<_>>();
+    Ok(df.select(columns)?)
+}
+
+/// The described columns of `df` and the weight expression.
+fn weighted_fields(df: &DataFrame) -> (Vec<Field>, Expr) {
+    let fields = df
+        .schema()
+        .fields()
+        .iter()
+        .filter(|f| f.name() != WEIGHT_COLUMN)
+        .map(|f| f.as_ref().clone())
+        .collect();
+    (fields, ident(WEIGHT_COLUMN))
+}
+
+/// Weight of the rows where `x` is not null.
+fn weight_of(x: &Expr, w: Expr) -> Expr {
+    case(is_null(x.clone()))
+        .when(lit(true), lit(ScalarValue::Float64(None)))
+        .otherwise(w)
+        .unwrap()
+}
+
+/// `sum(w * x) / sum(w)` over the non-null values of each column.
+fn weighted_mean(df: DataFrame) -> anyhow::Result<DataFrame> {
+    let (fields, w) = weighted_fields(&df);
+    let key = |f: &Field, stat: &str| format!("{}__{}", f.name(), stat);
+
+    let ret = df
+        .aggregate(
+            vec![],
+            fields
+                .iter()
+                .flat_map(|f| {
+                    let x = cast(ident(f.name()), DataType::Float64);
+                    [
+                        sum(w.clone() * x.clone()).alias(key(f, "wx")),
+                        sum(weight_of(&x, w.clone())).alias(key(f, "w")),
+                    ]
+                })
+                .collect::<Vec<_>>(),
+        )?
+        .select(
+            fields
+                .iter()
+                .map(|f| (ident(key(f, "wx")) / ident(key(f, "w"))).alias(f.name()))
+                .collect::<Vec<_>>(),
+        )?;
+    Ok(ret)
+}
+
+/// Weighted sample standard deviation, treating weights as frequencies:
+/// `sqrt((sum(w * x^2) - sum(w * x)^2 / sum(w)) / (sum(w) - 1))`, or the
+/// population one (dividing by `sum(w)`) if `population`.
+fn weighted_stddev(df: DataFrame, population: bool) -> anyhow::Result<DataFrame> {
+    let (fields, w) = weighted_fields(&df);
+    let key = |f: &Field, stat: &str| format!("{}__{}", f.name(), stat);
+
+    let ret = df
+        .aggregate(
+            vec![],
+            fields
+                .iter()
+                .flat_map(|f| {
+                    let x = cast(ident(f.name()), DataType::Float64);
+                    [
+                        sum(w.clone() * x.clone() * x.clone()).alias(key(f, "wxx")),
+                        sum(w.clone() * x.clone()).alias(key(f, "wx")),
+                        sum(weight_of(&x, w.clone())).alias(key(f, "w")),
+                    ]
+                })
+                .collect::<Vec<_>>(),
+        )?
+        .select(
+            fields
+                .iter()
+                .map(|f| {
+                    let (wxx, wx, w) = (
+                        ident(key(f, "wxx")),
+                        ident(key(f, "wx")),
+                        ident(key(f, "w")),
+                    );
+                    let dof = if population {
+                        w.clone()
+                    } else {
+                        w.clone() - lit(1.0)
+                    };
+                    let variance = (wxx - wx.clone() * wx / w) / dof;
+                    sqrt(variance).alias(f.name())
+                })
+                .collect::<Vec<_>>(),
+        )?;
+    Ok(ret)
+}
+
+fn weighted_percentile(df: DataFrame, percentile: f64) -> anyhow::Result<DataFrame> {
+    let (fields, w) = weighted_fields(&df);
+    let ret = df.aggregate(
+        vec![],
+        fields
+            .iter()
+            .map(|f| {
+                approx_percentile_cont_with_weight(
+                    cast(ident(f.name()), DataType::Float64),
+                    w.clone(),
+                    lit(percentile),
+                )
+                .alias(f.name())
+            })
+            .collect::<Vec<_>>(),
+    )?;
+    Ok(ret)
+}
+
+/// Statistic for columns a method doesn't apply to.
+fn null_stat() -> Expr {
+    max(lit(ScalarValue::Float64(None)))
+}
+
+fn whitespace_padded_count(df: DataFrame) -> anyhow::Result<DataFrame> {
+    let fields = df.schema().fields().iter();
+    let ret = df.clone().aggregate(
+        vec![],
+        fields
+            .map(|f| {
+                let expr = match f.data_type() {
+                    DataType::Utf8 | DataType::LargeUtf8 => {
+                        sum(case(btrim(vec![ident(f.name())]).not_eq(ident(f.name())))
+                            .when(lit(true), lit(1))
+                            .otherwise(lit(0))
+                            .unwrap())
+                    }
+                    _ => null_stat(),
+                };
+                expr.alias(f.name())
+            })
+            .collect::<Vec<_>>(),
+    )?;
+    Ok(ret)
+}
+
+/// Number of empty (`empty`) or non-empty strings per string column, nulls
+/// are neither.
+fn string_fill_count(df: DataFrame, empty: bool) -> anyhow::Result<DataFrame> {
+    let fields = df.schema().fields().iter();
+    let ret = df.clone().aggregate(
+        vec![],
+        fields
+            .map(|f| {
+                let expr = match f.data_type() {
+                    DataType::Utf8 | DataType::LargeUtf8 => {
+                        let matches = if empty {
+                            ident(f.name()).eq(lit(""))
+                        } else {
+                            ident(f.name()).not_eq(lit(""))
+                        };
+                        count(case(matches).when(lit(true), lit(1)).end().unwrap())
+                    }
+                    _ => null_stat(),
+                };
+                expr.alias(f.name())
+            })
+            .collect::<Vec<_>>(),
+    )?;
+    Ok(ret)
+}
+
+fn percentile(
+    df: DataFrame,
+    percentile: f64,
+    udaf: Option<&AggregateUDF>,
+) -> anyhow::Result<DataFrame> {
+    let fields = df.schema().fields().iter();
+    let ret = df.clone().aggregate(
+        vec![],
+        fields
+            .filter(|f| f.data_type().is_numeric())
+            .map(|f| {
+                let expr = match udaf {
+                    Some(udaf) => udaf.call(vec![ident(f.name()), lit(percentile)]),
+                    None => approx_percentile_cont(ident(f.name()), lit(percentile)),
+                };
+                expr.alias(f.name())
+            })
+            .collect::<Vec<_>>(),
+    )?;
+    Ok(ret)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow::array::{
+        ArrayRef, BinaryArray, Date32Array, DictionaryArray, Float64Array, Int32Array,
+        Int32Builder, Int64Array, Int8Array, LargeBinaryArray, MapBuilder, StringArray,
+        StringBuilder, StructArray, TimestampNanosecondArray, UInt64Array,
+    };
+    use arrow::datatypes::{Fields, Schema, TimeUnit};
+    use arrow::datatypes::{Int32Type, Int8Type};
+    use arrow::ipc::reader::StreamReader;
+    use arrow::record_batch::RecordBatch;
+    use assert_approx_eq::assert_approx_eq;
+    use datafusion::{
+        datasource::MemTable,
+        logical_expr::{create_udaf, Accumulator, Volatility},
+    };
+    use std::sync::Arc;
+
+    /// Look up the stringified value of `column` in the row labeled `label`.
+    fn stat_value(batches: &[RecordBatch], label: &str, column: &str) -> String {
+        for batch in batches {
+            let labels = batch
+                .column_by_name("describe")
+                .unwrap()
+                .as_any()
+                .downcast_ref::<StringArray>()
+                .unwrap();
+            let values = batch.column_by_name(column).unwrap();
+            if let Some(row) = (0..batch.num_rows()).find(|&i| labels.value(i) == label) {
+                return array_value_to_string(values, row).unwrap();
+            }
+        }
+        panic!("no row labeled {label}");
+    }
+
+    /// The `describe` labels of all rows, in order.
+    fn describe_labels(batches: &[RecordBatch]) -> Vec<String> {
+        batches
+            .iter()
+            .flat_map(|b| {
+                let labels = b
+                    .column_by_name("describe")
+                    .unwrap()
+                    .as_any()
+                    .downcast_ref::<StringArray>()
+                    .unwrap();
+                labels
+                    .iter()
+                    .flatten()
+                    .map(String::from)
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+
+    fn create_test_dataframe() -> DataFrame {
+        // Create a simple RecordBatch
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("float_col", DataType::Float64, false),
+            Field::new("int_col", DataType::Int32, false),
+            Field::new("string_col", DataType::Utf8, false),
+        ]));
+
+        let batch = RecordBatch::try_new(
+            schema.clone(),
+            vec![
+                Arc::new(Float64Array::from(vec![1.0, 2.0, 3.0, 4.0])) as ArrayRef,
+                Arc::new(Int32Array::from(vec![4, 5, 6, 7])) as ArrayRef,
+                Arc::new(StringArray::from(vec!["a", "b", "c", "d"])) as ArrayRef,
+            ],
+        )
+        .unwrap();
+
+        // Convert the RecordBatch to a DataFrame
+        let ctx = SessionContext::new();
+        ctx.read_batch(batch).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_try_new() {
+        let df = create_test_dataframe();
+        let describer = DataFrameDescriber::try_new(df);
+        assert!(describer.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_describe_total() {
+        let df = create_test_dataframe();
+        let describer = DataFrameDescriber::try_new(df).unwrap();
+
+        let described_df = describer.describe().await;
+        assert!(described_df.is_ok());
+
+        let result = described_df.unwrap().collect().await.unwrap();
+        assert_eq!(result.len(), 1); // should have one row for each statistic
+        assert_eq!(result[0].num_columns(), 4); // describe + three columns
+    }
+    #[tokio::test]
+    async fn test_percentile() {
+        let df = create_test_dataframe();
+        let percentile_df = percentile(df.clone(), 0.5, None).unwrap();
+
+        let result = percentile_df.collect().await.unwrap();
+
+        // Verify the percentile values
+        assert_eq!(result.len(), 1); // should have one row for the percentile
+        assert_eq!(result[0].num_columns(), 2); // percentile + one column
+
+        let float_col = result[0]
+            .column(0)
+            .as_any()
+            .downcast_ref::<Float64Array>()
+            .unwrap();
+        assert_eq!(float_col.value(0), 2.5); // median of [1.0, 2.0, 3.0, 4.0] is 2.5
+
+        let int_col = result[0]
+            .column(1)
+            .as_any()
+            .downcast_ref::<Int32Array>()
+            .unwrap();
+        assert_eq!(int_col.value(0), 5); // median of [4, 5, 6, 7] is 5.5 but approx_percentile_cont rounds down to 5
+    }
+
+    #[tokio::test]
+    async fn test_detect_duplicate_rows() {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("int_col", DataType::Int32, false),
+            Field::new("string_col", DataType::Utf8, false),
+        ]));
+        let batch = RecordBatch::try_new(
+            schema,
+            vec![
+                Arc::new(Int32Array::from(vec![1, 1, 2, 3, 3])) as ArrayRef,
+                Arc::new(StringArray::from(vec!["a", "a", "b", "c", "d"])) as ArrayRef,
+            ],
+        )
+        .unwrap();
+        let df = SessionContext::new().read_batch(batch).unwrap();
+        let describer = DataFrameDescriber::try_new(df).unwrap();
+
+        let (total, duplicates) = describer.detect_duplicate_rows().await.unwrap();
+        assert_eq!(total, 5);
+        assert_eq!(duplicates, 1); // only (1, "a") appears more than once
+    }
+
+    #[tokio::test]
+    async fn test_is_constant() {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("constant_col", DataType::Int32, false),
+            Field::new("varying_col", DataType::Int32, false),
+        ]));
+        let batch = RecordBatch::try_new(
+            schema,
+            vec![
+                Arc::new(Int32Array::from(vec![5, 5, 5])) as ArrayRef,
+                Arc::new(Int32Array::from(vec![1, 2, 3])) as ArrayRef,
+            ],
+        )
+        .unwrap();
+        let df = SessionContext::new().read_batch(batch).unwrap();
+        let describer = DataFrameDescriber::try_new(df)
+            .unwrap()
+            .with_methods(vec![DescribeMethod::IsConstant]);
+
+        let result = describer.describe().await.unwrap().collect().await.unwrap();
+        assert_eq!(stat_value(&result, "is_constant", "constant_col"), "true");
+        assert_eq!(stat_value(&result, "is_constant", "varying_col"), "false");
+    }
+
+    #[tokio::test]
+    async fn test_binary_columns() {
+        let batch = RecordBatch::try_new(
+            Arc::new(Schema::new(vec![
+                Field::new("bytes", DataType::Binary, true),
+                Field::new("large_bytes", DataType::LargeBinary, false),
+            ])),
+            vec![
+                Arc::new(BinaryArray::from(vec![
+                    Some(b"\x00\xff".as_ref()),
+                    None,
+                    Some(b"\x80\x81\x82\x83".as_ref()),
+                ])) as ArrayRef,
+                Arc::new(LargeBinaryArray::from(vec![
+                    b"".as_ref(),
+                    b"\xde\xad".as_ref(),
+                    b"\xbe\xef\x00".as_ref(),
+                ])) as ArrayRef,
+            ],
+        )
+        .unwrap();
+        let df = SessionContext::new().read_batch(batch).unwrap();
+        let describer = DataFrameDescriber::try_new(df).unwrap().with_methods(vec![
+            DescribeMethod::Total,
+            DescribeMethod::Min,
+            DescribeMethod::Max,
+            DescribeMethod::Mean,
+        ]);
+
+        let result = describer.describe().await.unwrap().collect().await.unwrap();
+        assert_eq!(stat_value(&result, "total", "bytes"), "2.0");
+        assert_eq!(stat_value(&result, "min", "bytes"), "2.0");
+        assert_eq!(stat_value(&result, "max", "bytes"), "4.0");
+        assert_eq!(stat_value(&result, "min", "large_bytes"), "0.0");
+        assert_eq!(
+            stat_value(&result, "mean", "large_bytes"),
+            "1.6666666666666667"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_range_utilization() {
+        let batch = RecordBatch::try_new(
+            Arc::new(Schema::new(vec![
+                Field::new("small", DataType::Int32, false),
+                Field::new("full", DataType::Int8, false),
+                Field::new("x", DataType::Float64, false),
+            ])),
+            vec![
+                Arc::new(Int32Array::from(vec![0, 50, 100])) as ArrayRef,
+                Arc::new(Int8Array::from(vec![-128, 0, 127])) as ArrayRef,
+                Arc::new(Float64Array::from(vec![0.0, 1.0, 2.0])) as ArrayRef,
+            ],
+        )
+        .unwrap();
+        let df = SessionContext::new().read_batch(batch).unwrap();
+        let describer = DataFrameDescriber::try_new(df)
+            .unwrap()
+            .with_methods(vec![DescribeMethod::RangeUtilization]);
+
+        let stats = describer.describe_map().await.unwrap();
+        let small = stats["small"]["range_utilization"].unwrap();
+        assert!(small > 0.0 && small < 1e-7);
+        assert_approx_eq!(small, 100.0 / (u32::MAX as f64));
+        assert_approx_eq!(stats["full"]["range_utilization"].unwrap(), 1.0);
+        assert_eq!(stats["x"]["range_utilization"], None);
+    }
+
+    #[tokio::test]
+    async fn test_has_mixed_sign() {
+        let batch = RecordBatch::try_new(
+            Arc::new(Schema::new(vec![
+                Field::new("mixed", DataType::Float64, true),
+                Field::new("positive", DataType::Float64, true),
+                Field::new("empty", DataType::Float64, true),
+                Field::new("label", DataType::Utf8, true),
+            ])),
+            vec![
+                Arc::new(Float64Array::from(vec![-1.0, 2.0])) as ArrayRef,
+                Arc::new(Float64Array::from(vec![1.0, 2.0])) as ArrayRef,
+                Arc::new(Float64Array::from(vec![None, None])) as ArrayRef,
+                Arc::new(StringArray::from(vec!["a", "b"])) as ArrayRef,
+            ],
+        )
+        .unwrap();
+        let df = SessionContext::new().read_batch(batch).unwrap();
+        let describer = DataFrameDescriber::try_new(df)
+            .unwrap()
+            .with_methods(vec![DescribeMethod::HasMixedSign]);
+
+        let result = describer.describe().await.unwrap().collect().await.unwrap();
+        assert_eq!(stat_value(&result, "has_mixed_sign", "mixed"), "true");
+        assert_eq!(stat_value(&result, "has_mixed_sign", "positive"), "false");
+        assert_eq!(stat_value(&result, "has_mixed_sign", "empty"), "");
+        assert_eq!(stat_value(&result, "has_mixed_sign", "label"), "");
+    }
+
+    #[tokio::test]
+    async fn test_describe_map() {
+        let df = create_test_dataframe();
+        let describer = DataFrameDescriber::try_new(df).unwrap();
+
+        let result = describer.describe_map().await.unwrap();
+        assert_eq!(result["int_col"]["mean"], Some(5.5));
+        assert_eq!(result["float_col"]["total"], Some(4.0));
+        assert_eq!(result.len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_describe_to_ipc_stream() {
+        let df = create_test_dataframe();
+        let describer = DataFrameDescriber::try_new(df).unwrap();
+
+        let mut buf = Vec::new();
+        describer.describe_to_ipc_stream(&mut buf).await.unwrap();
+
+        let reader = StreamReader::try_new(buf.as_slice(), None).unwrap();
+        let actual = reader.collect::<Result<Vec<_>, _>>().unwrap();
+        let expected = describer.describe().await.unwrap().collect().await.unwrap();
+        assert_eq!(actual, expected);
+    }
+
+    #[tokio::test]
+    async fn test_rolling_describe() {
+        let schema = Arc::new(Schema::new(vec![Field::new(
+            "int_col",
+            DataType::Int32,
+            false,
+        )]));
+        let batch = RecordBatch::try_new(
+            schema,
+            vec![Arc::new(Int32Array::from((1..=10).collect::<Vec<_>>())) as ArrayRef],
+        )
+        .unwrap();
+        let df = SessionContext::new().read_batch(batch).unwrap();
+        let describer = DataFrameDescriber::try_new(df)
+            .unwrap()
+            .with_methods(vec![DescribeMethod::Total, DescribeMethod::Mean]);
+
+        let windows = describer.rolling_describe(5, 2).await.unwrap();
+        assert_eq!(windows.len(), 3);
+        for (window, expected_mean) in windows.into_iter().zip(["3.0", "5.0", "7.0"]) {
+            let result = window.collect().await.unwrap();
+            assert_eq!(stat_value(&result, "total", "int_col"), "5.0");
+            assert_eq!(stat_value(&result, "mean", "int_col"), expected_mean);
+        }
+
+        assert!(describer.rolling_describe(11, 2).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_string_fill_counts() {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("string_col", DataType::Utf8, true),
+            Field::new("int_col", DataType::Int32, false),
+        ]));
+        let batch = RecordBatch::try_new(
+            schema,
+            vec![
+                Arc::new(StringArray::from(vec![
+                    None,
+                    Some(""),
+                    Some("x"),
+                    Some("y"),
+                ])) as ArrayRef,
+                Arc::new(Int32Array::from(vec![1, 2, 3, 4])) as ArrayRef,
+            ],
+        )
+        .unwrap();
+        let df = SessionContext::new().read_batch(batch).unwrap();
+        let describer = DataFrameDescriber::try_new(df).unwrap().with_methods(vec![
+            DescribeMethod::NullTotal,
+            DescribeMethod::EmptyCount,
+            DescribeMethod::NonEmptyCount,
+        ]);
+
+        let result = describer.describe().await.unwrap().collect().await.unwrap();
+        assert_eq!(stat_value(&result, "null_total", "string_col"), "1.0");
+        assert_eq!(stat_value(&result, "empty_count", "string_col"), "1.0");
+        assert_eq!(stat_value(&result, "nonempty_count", "string_col"), "2.0");
+        assert_eq!(stat_value(&result, "empty_count", "int_col"), "");
+        assert_eq!(
+            "nonempty_count"
+                .parse::<DescribeMethod>()
+                .unwrap()
+                .to_string(),
+            "nonempty_count"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_whitespace_padded_count() {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("string_col", DataType::Utf8, false),
+            Field::new("int_col", DataType::Int32, false),
+        ]));
+        let batch = RecordBatch::try_new(
+            schema,
+            vec![
+                Arc::new(StringArray::from(vec!["ok", " pad", "end "])) as ArrayRef,
+                Arc::new(Int32Array::from(vec![1, 2, 3])) as ArrayRef,
+            ],
+        )
+        .unwrap();
+        let df = SessionContext::new().read_batch(batch).unwrap();
+        let describer = DataFrameDescriber::try_new(df)
+            .unwrap()
+            .with_methods(vec![DescribeMethod::WhitespacePaddedCount]);
+
+        let result = describer.describe().await.unwrap().collect().await.unwrap();
+        assert_eq!(
+            stat_value(&result, "whitespace_padded_count", "string_col"),
+            "2.0"
+        );
+        assert_eq!(
+            stat_value(&result, "whitespace_padded_count", "int_col"),
+            ""
+        );
+    }
+
+    #[tokio::test]
+    async fn test_feature_importance_proxy() {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("steady_col", DataType::Float64, false),
+            Field::new("spread_col", DataType::Float64, false),
+        ]));
+        let batch = RecordBatch::try_new(
+            schema,
+            vec![
+                Arc::new(Float64Array::from(vec![10.0, 10.0, 10.0, 11.0])) as ArrayRef,
+                Arc::new(Float64Array::from(vec![1.0, 2.0, 3.0, 4.0])) as ArrayRef,
+            ],
+        )
+        .unwrap();
+        let df = SessionContext::new().read_batch(batch).unwrap();
+        let describer = DataFrameDescriber::try_new(df).unwrap();
+
+        let result = describer
+            .feature_importance_proxy()
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        let names = result[0]
+            .column_by_name("column_name")
+            .unwrap()
+            .as_any()
+            .downcast_ref::<StringArray>()
+            .unwrap();
+        assert_eq!(names.value(0), "spread_col");
+        assert_eq!(names.value(1), "steady_col");
+    }
+
+    #[tokio::test]
+    async fn test_detect_skewed_columns() {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("exp_col", DataType::Float64, false),
+            Field::new("normal_col", DataType::Float64, false),
+        ]));
+        let batch = RecordBatch::try_new(
+            schema,
+            vec![
+                Arc::new(Float64Array::from(vec![
+                    1.0, 1.0, 1.0, 1.0, 2.0, 2.0, 3.0, 5.0, 10.0, 30.0,
+                ])) as ArrayRef,
+                Arc::new(Float64Array::from(vec![
+                    1.0, 2.0, 3.0, 4.0, 5.0, 5.0, 4.0, 3.0, 2.0, 1.0,
+                ])) as ArrayRef,
+            ],
+        )
+        .unwrap();
+        let df = SessionContext::new().read_batch(batch).unwrap();
+        let describer = DataFrameDescriber::try_new(df).unwrap();
+
+        let skewed = describer.detect_skewed_columns(1.0).await.unwrap();
+        assert_eq!(skewed, vec!["exp_col".to_string()]);
+    }
+
+    #[tokio::test]
+    async fn test_max_decimal_places() {
+        let schema = Arc::new(Schema::new(vec![Field::new(
+            "float_col",
+            DataType::Float64,
+            false,
+        )]));
+        let batch = RecordBatch::try_new(
+            schema,
+            vec![Arc::new(Float64Array::from(vec![1.5, 2.25, 3.0])) as ArrayRef],
+        )
+        .unwrap();
+        let df = SessionContext::new().read_batch(batch).unwrap();
+        let describer = DataFrameDescriber::try_new(df)
+            .unwrap()
+            .with_methods(vec![DescribeMethod::MaxDecimalPlaces]);
+
+        let result = describer.describe().await.unwrap().collect().await.unwrap();
+        assert_eq!(
+            stat_value(&result, "max_decimal_places", "float_col"),
+            "2.0"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_describe_materializes_summary_only() {
+        let schema = Arc::new(Schema::new(vec![Field::new(
+            "int_col",
+            DataType::Int32,
+            false,
+        )]));
+        let batches = (0..10)
+            .map(|i| {
+                RecordBatch::try_new(
+                    schema.clone(),
+                    vec![
+                        Arc::new(Int32Array::from_iter_values(i * 10_000..(i + 1) * 10_000))
+                            as ArrayRef,
+                    ],
+                )
+                .unwrap()
+            })
+            .collect::<Vec<_>>();
+        let table = MemTable::try_new(schema, vec![batches]).unwrap();
+        let df = SessionContext::new().read_table(Arc::new(table)).unwrap();
+        let describer = DataFrameDescriber::try_new(df).unwrap();
+        let methods = describer.methods.len();
+
+        let result = describer.do_describe().unwrap().collect().await.unwrap();
+        assert!(result.len() <= methods);
+        assert_eq!(result.iter().map(|b| b.num_rows()).sum::<usize>(), methods);
+
+        let stats = describer.describe_map().await.unwrap();
+        assert_eq!(stats["int_col"]["total"], Some(100_000.0));
+    }
+
+    #[tokio::test]
+    async fn test_value_range_check() {
+        let df = create_test_dataframe();
+        let describer = DataFrameDescriber::try_new(df).unwrap();
+
+        assert!(describer
+            .value_range_check("int_col", 0.0, 10.0)
+            .await
+            .unwrap());
+        assert!(!describer
+            .value_range_check("int_col", 5.0, 10.0)
+            .await
+            .unwrap());
+
+        let err = describer
+            .value_range_check("missing_col", 0.0, 10.0)
+            .await
+            .unwrap_err();
+        assert!(err.downcast_ref::<RangeCheckError>().is_some());
+    }
+
+    #[tokio::test]
+    async fn test_sensitivity_report() {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("user_email", DataType::Utf8, false),
+            Field::new("category", DataType::Utf8, false),
+            Field::new("national_id", DataType::Int64, false),
+            Field::new("age", DataType::Int32, false),
+            Field::new("amount", DataType::Int64, false),
+            Field::new("short_id", DataType::Int64, false),
+            Field::new("tax_ref", DataType::Float64, false),
+            Field::new("price", DataType::Float64, false),
+        ]));
+        let batch = RecordBatch::try_new(
+            schema,
+            vec![
+                Arc::new(StringArray::from(vec![
+                    "a@x.io", "b@x.io", "c@x.io", "d@x.io",
+                ])) as ArrayRef,
+                Arc::new(StringArray::from(vec!["a", "b", "a", "b"])) as ArrayRef,
+                Arc::new(Int64Array::from(vec![
+                    123456789, 987654321, 555443333, 100200300,
+                ])) as ArrayRef,
+                Arc::new(Int32Array::from(vec![21, 35, 42, 67])) as ArrayRef,
+                // the maximum alone is in the SSN range
+                Arc::new(Int64Array::from(vec![0, 5, 5, 123456789])) as ArrayRef,
+                // a leading zero (012345678) is below the checked range
+                Arc::new(Int64Array::from(vec![
+                    12345678, 987654321, 555443333, 100200300,
+                ])) as ArrayRef,
+                Arc::new(Float64Array::from(vec![
+                    123456789.0,
+                    987654321.0,
+                    555443333.0,
+                    100200300.0,
+                ])) as ArrayRef,
+                Arc::new(Float64Array::from(vec![
+                    123456789.5,
+                    987654321.0,
+                    555443333.0,
+                    100200300.0,
+                ])) as ArrayRef,
+            ],
+        )
+        .unwrap();
+        let df = SessionContext::new().read_batch(batch).unwrap();
+        let describer = DataFrameDescriber::try_new(df).unwrap();
+
+        let flags = describer.sensitivity_report().await.unwrap();
+        let flagged = |column: &str| flags.iter().filter(|f| f.column == column).count();
+        assert_eq!(flagged("user_email"), 2); // name hint + high cardinality
+        assert_eq!(flagged("category"), 0);
+        assert_eq!(flagged("national_id"), 1);
+        assert_eq!(flagged("age"), 0);
+        assert_eq!(flagged("amount"), 0);
+        assert_eq!(flagged("short_id"), 0);
+        assert_eq!(flagged("tax_ref"), 1);
+        assert_eq!(flagged("price"), 0);
+    }
+
+    #[tokio::test]
+    async fn test_with_absolute_columns() {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("abs_col", DataType::Float64, false),
+            Field::new("raw_col", DataType::Float64, false),
+        ]));
+        let batch = RecordBatch::try_new(
+            schema,
+            vec![
+                Arc::new(Float64Array::from(vec![-1.0, -2.0, 3.0])) as ArrayRef,
+                Arc::new(Float64Array::from(vec![-1.0, -2.0, 3.0])) as ArrayRef,
+            ],
+        )
+        .unwrap();
+        let df = SessionContext::new().read_batch(batch).unwrap();
+        let describer = DataFrameDescriber::try_new(df)
+            .unwrap()
+            .with_absolute_columns(&["abs_col"])
+            .unwrap();
+
+        let stats = describer.describe_map().await.unwrap();
+        assert_eq!(stats["abs_col"]["mean"], Some(2.0));
+        assert_eq!(stats["raw_col"]["mean"], Some(0.0));
+    }
+
+    #[tokio::test]
+    async fn test_pairwise_stat() {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int32, false),
+            Field::new("b", DataType::Float64, false),
+        ]));
+        let batch = RecordBatch::try_new(
+            schema,
+            vec![
+                Arc::new(Int32Array::from(vec![1, 2, 3, 4])) as ArrayRef,
+                Arc::new(Float64Array::from(vec![2.0, 4.0, 6.0, 8.0])) as ArrayRef,
+            ],
+        )
+        .unwrap();
+        let df = SessionContext::new().read_batch(batch).unwrap();
+        let describer = DataFrameDescriber::try_new(df).unwrap();
+
+        let cov = describer
+            .pairwise_stat("a", "b", PairwiseMethod::Covariance)
+            .await
+            .unwrap();
+        assert_approx_eq!(cov, 10.0 / 3.0);
+        let corr = describer
+            .pairwise_stat("a", "b", PairwiseMethod::Correlation)
+            .await
+            .unwrap();
+        assert_approx_eq!(corr, 1.0);
+    }
+
+    #[tokio::test]
+    async fn test_describe_map_column() {
+        let mut builder = MapBuilder::new(None, StringBuilder::new(), Int32Builder::new());
+        for entries in [
+            vec![("a", 1), ("b", 2)],
+            vec![("c", 3)],
+            vec![("d", 4), ("e", 5), ("f", 6)],
+        ] {
+            for (k, v) in entries {
+                builder.keys().append_value(k);
+                builder.values().append_value(v);
+            }
+            builder.append(true).unwrap();
+        }
+        let map = builder.finish();
+        let schema = Arc::new(Schema::new(vec![Field::new(
+            "map_col",
+            map.data_type().clone(),
+            false,
+        )]));
+        let batch = RecordBatch::try_new(schema, vec![Arc::new(map) as ArrayRef]).unwrap();
+        let df = SessionContext::new().read_batch(batch).unwrap();
+        let describer = DataFrameDescriber::try_new(df).unwrap().with_methods(vec![
+            DescribeMethod::Total,
+            DescribeMethod::Mean,
+            DescribeMethod::Min,
+            DescribeMethod::Max,
+        ]);
+
+        let result = describer.describe().await.unwrap().collect().await.unwrap();
+        assert_eq!(stat_value(&result, "total", "map_col"), "3");
+        assert_eq!(stat_value(&result, "mean", "map_col"), "2");
+        assert_eq!(stat_value(&result, "min", "map_col"), "1");
+        assert_eq!(stat_value(&result, "max", "map_col"), "3");
+    }
+
+    #[tokio::test]
+    async fn test_min_max_scale() {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("int_col", DataType::Int32, false),
+            Field::new("constant_col", DataType::Float64, false),
+            Field::new("string_col", DataType::Utf8, false),
+        ]));
+        let batch = RecordBatch::try_new(
+            schema,
+            vec![
+                Arc::new(Int32Array::from(vec![1, 2, 3])) as ArrayRef,
+                Arc::new(Float64Array::from(vec![5.0, 5.0, 5.0])) as ArrayRef,
+                Arc::new(StringArray::from(vec!["a", "b", "c"])) as ArrayRef,
+            ],
+        )
+        .unwrap();
+        let df = SessionContext::new().read_batch(batch).unwrap();
+        let describer = DataFrameDescriber::try_new(df).unwrap();
+
+        let scaled = describer.min_max_scale().await.unwrap();
+        let result = scaled.collect().await.unwrap();
+        let column = |name: &str| {
+            result[0]
+                .column_by_name(name)
+                .unwrap()
+                .as_any()
+                .downcast_ref::<Float64Array>()
+                .unwrap()
+                .values()
+                .to_vec()
+        };
+        assert_eq!(column("int_col"), vec![0.0, 0.5, 1.0]);
+        assert_eq!(column("constant_col"), vec![0.0, 0.0, 0.0]);
+        assert_eq!(
+            result[0]
+                .schema()
+                .field_with_name("string_col")
+                .unwrap()
+                .data_type(),
+            &DataType::Utf8
+        );
+    }
+
+    #[tokio::test]
+    async fn test_percentile_fraction() {
+        let method = DescribeMethod::percentile_fraction(0.999).unwrap();
+        assert_eq!(method.to_string(), "percentile_0.999");
+        assert!(matches!(
+            "percentile_0.999".parse::<DescribeMethod>().unwrap(),
+            DescribeMethod::PercentileF(p) if p == 0.999
+        ));
+        assert!(matches!(
+            "percentile_1.0".parse::<DescribeMethod>().unwrap(),
+            DescribeMethod::PercentileF(p) if p == 1.0
+        ));
+        assert!(matches!(
+            "percentile_25".parse::<DescribeMethod>().unwrap(),
+            DescribeMethod::Percentile(25)
+        ));
+        assert!(DescribeMethod::percentile_fraction(1.5).is_err());
+        assert!("percentile_1.5".parse::<DescribeMethod>().is_err());
+
+        for p in [0.0, 1e-5, 1e-12, 0.123456789, 0.5, 0.99999, 1.0] {
+            let label = DescribeMethod::percentile_fraction(p).unwrap().to_string();
+            assert!(
+                matches!(
+                    label.parse::<DescribeMethod>().unwrap(),
+                    DescribeMethod::PercentileF(q) if q == p
+                ),
+                "{} does not round-trip",
+                label
+            );
+        }
+        assert_eq!(
+            DescribeMethod::PercentileF(1e-5).to_string(),
+            "percentile_0.00001"
+        );
+        assert!(matches!(
+            "percentile_1e-5".parse::<DescribeMethod>().unwrap(),
+            DescribeMethod::PercentileF(p) if p == 1e-5
+        ));
+
+        let describer = DataFrameDescriber::try_new(create_test_dataframe())
+            .unwrap()
+            .with_methods(vec![method]);
+        let result = describer.describe().await.unwrap().collect().await.unwrap();
+        assert_eq!(stat_value(&result, "percentile_0.999", "float_col"), "4.0");
+    }
+
+    #[derive(Debug)]
+    struct ConstAccumulator;
+
+    impl Accumulator for ConstAccumulator {
+        fn update_batch(&mut self, _values: &[ArrayRef]) -> datafusion::error::Result<()> {
+            Ok(())
+        }
+
+        fn evaluate(&mut self) -> datafusion::error::Result<ScalarValue> {
+            Ok(ScalarValue::Float64(Some(42.0)))
+        }
+
+        fn size(&self) -> usize {
+            std::mem::size_of_val(self)
+        }
+
+        fn state(&mut self) -> datafusion::error::Result<Vec<ScalarValue>> {
+            Ok(vec![])
+        }
+
+        fn merge_batch(&mut self, _states: &[ArrayRef]) -> datafusion::error::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_with_percentile_udf() {
+        let udaf = create_udaf(
+            "const_percentile",
+            vec![DataType::Float64, DataType::Float64],
+            Arc::new(DataType::Float64),
+            Volatility::Immutable,
+            Arc::new(|_| Ok(Box::new(ConstAccumulator))),
+            Arc::new(vec![]),
+        );
+        let df = create_test_dataframe();
+        let describer = DataFrameDescriber::try_new(df)
+            .unwrap()
+            .with_methods(vec![DescribeMethod::Percentile(50)])
+            .with_percentile_udf(Arc::new(udaf))
+            .unwrap();
+
+        let result = describer.describe().await.unwrap().collect().await.unwrap();
+        assert_eq!(stat_value(&result, "percentile_50", "float_col"), "42.0");
+        assert_eq!(stat_value(&result, "percentile_50", "int_col"), "42.0");
+    }
+
+    #[test]
+    fn test_with_percentile_udf_rejects_bad_signature() {
+        let udaf = create_udaf(
+            "one_arg",
+            vec![DataType::Float64],
+            Arc::new(DataType::Float64),
+            Volatility::Immutable,
+            Arc::new(|_| Ok(Box::new(ConstAccumulator))),
+            Arc::new(vec![]),
+        );
+        let describer = DataFrameDescriber::try_new(create_test_dataframe()).unwrap();
+        assert!(describer.with_percentile_udf(Arc::new(udaf)).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_describe_struct_column() {
+        let fields = Fields::from(vec![
+            Field::new("x", DataType::Float64, false),
+            Field::new("y", DataType::Int32, false),
+        ]);
+        let point = StructArray::new(
+            fields.clone(),
+            vec![
+                Arc::new(Float64Array::from(vec![1.0, 2.0, 3.0])) as ArrayRef,
+                Arc::new(Int32Array::from(vec![10, 20, 30])) as ArrayRef,
+            ],
+            None,
+        );
+        let schema = Arc::new(Schema::new(vec![Field::new(
+            "point",
+            DataType::Struct(fields),
+            false,
+        )]));
+        let batch = RecordBatch::try_new(schema, vec![Arc::new(point) as ArrayRef]).unwrap();
+        let df = SessionContext::new().read_batch(batch).unwrap();
+
+        let describer = DataFrameDescriber::try_new(df)
+            .unwrap()
+            .with_methods(vec![DescribeMethod::Total, DescribeMethod::Mean]);
+        let result = describer.describe().await.unwrap().collect().await.unwrap();
+        assert_eq!(stat_value(&result, "total", "point.x"), "3.0");
+        assert_eq!(stat_value(&result, "mean", "point.x"), "2.0");
+        assert_eq!(stat_value(&result, "total", "point.y"), "3.0");
+        assert_eq!(stat_value(&result, "mean", "point.y"), "20.0");
+    }
+
+    #[tokio::test]
+    async fn test_describe_remote() {
+        let ctx = SessionContext::new();
+        // local files aren't remote, even though ctx has a store for them
+        let url = format!(
+            "file://{}/assets/sample.parquet",
+            env!("CARGO_MANIFEST_DIR")
+        );
+        assert!(DataFrameDescriber::describe_remote(&url, &ctx)
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_describe_remote_registers_store() {
+        let ctx = SessionContext::new();
+        let url = "s3://bucket/data.parquet";
+        let store_url = ListingTableUrl::parse(url).unwrap().object_store();
+        assert!(ctx.runtime_env().object_store(&store_url).is_err());
+        register_object_store(&ctx, url).unwrap();
+        assert!(ctx.runtime_env().object_store(&store_url).is_ok());
+        // an existing store is kept
+        register_object_store(&ctx, url).unwrap();
+
+        assert!(register_object_store(&ctx, "ftp://host/data.parquet").is_err());
+        assert!(
+            DataFrameDescriber::describe_remote("ftp://host/data.parquet", &ctx)
+                .await
+                .is_err()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_with_output_batch_size() {
+        let df = create_test_dataframe();
+        let describer = DataFrameDescriber::try_new(df)
+            .unwrap()
+            .with_output_batch_size(3)
+            .unwrap();
+
+        let result = describer.describe().await.unwrap().collect().await.unwrap();
+        assert!(result.iter().all(|b| b.num_rows() <= 3));
+        assert_eq!(result.iter().map(|b| b.num_rows()).sum::<usize>(), 10);
+        assert_eq!(stat_value(&result, "mean", "int_col"), "5.5");
+
+        let describer = DataFrameDescriber::try_new(create_test_dataframe()).unwrap();
+        assert!(describer.with_output_batch_size(0).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_confidence_interval() {
+        // n = 4 uses the t distribution: 2.5 ± 3.182 * 1.291 / 2
+        let describer = DataFrameDescriber::try_new(create_test_dataframe()).unwrap();
+        let (lower, upper) = describer
+            .confidence_interval("float_col", 0.95)
+            .await
+            .unwrap();
+        assert_approx_eq!(lower, 0.4457, 1e-2);
+        assert_approx_eq!(upper, 4.5543, 1e-2);
+
+        // n = 100 uses the normal distribution: 49.5 ± 1.96 * 29.011 / 10
+        let batch = RecordBatch::try_new(
+            Arc::new(Schema::new(vec![Field::new("x", DataType::Int32, false)])),
+            vec![Arc::new(Int32Array::from_iter_values(0..100)) as ArrayRef],
+        )
+        .unwrap();
+        let df = SessionContext::new().read_batch(batch).unwrap();
+        let describer = DataFrameDescriber::try_new(df).unwrap();
+        let (lower, upper) = describer.confidence_interval("x", 0.95).await.unwrap();
+        assert_approx_eq!(lower, 43.814, 1e-3);
+        assert_approx_eq!(upper, 55.186, 1e-3);
+
+        assert!(describer.confidence_interval("x", 1.5).await.is_err());
+        assert!(describer
+            .confidence_interval("missing", 0.95)
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_describe_with_metrics() {
+        let describer = DataFrameDescriber::try_new(create_test_dataframe()).unwrap();
+        let (df, metrics) = describer.describe_with_metrics().await.unwrap();
+
+        assert_eq!(metrics.output_rows, 10);
+        assert!(metrics.elapsed > Duration::ZERO);
+        let result = df.collect().await.unwrap();
+        assert_eq!(stat_value(&result, "mean", "float_col"), "2.5");
+
+        let (df, _) = DataFrameDescriber::try_new(create_test_dataframe())
+            .unwrap()
+            .with_version_metadata(true)
+            .describe_with_metrics()
+            .await
+            .unwrap();
+        assert_eq!(
+            df.schema().metadata().get(SCHEMA_VERSION_KEY),
+            Some(&SCHEMA_VERSION.to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_column_correlation_with_target() {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("target", DataType::Float64, false),
+            Field::new("same", DataType::Int32, false),
+            Field::new("inverse", DataType::Float64, false),
+            Field::new("noise", DataType::Float64, false),
+            Field::new("name", DataType::Utf8, false),
+        ]));
+        let batch = RecordBatch::try_new(
+            schema,
+            vec![
+                Arc::new(Float64Array::from(vec![1.0, 2.0, 3.0, 4.0])) as ArrayRef,
+                Arc::new(Int32Array::from(vec![2, 4, 6, 8])) as ArrayRef,
+                Arc::new(Float64Array::from(vec![-1.0, -2.0, -3.0, -4.5])) as ArrayRef,
+                Arc::new(Float64Array::from(vec![1.0, -1.0, -1.0, 1.0])) as ArrayRef,
+                Arc::new(StringArray::from(vec!["a", "b", "c", "d"])) as ArrayRef,
+            ],
+        )
+        .unwrap();
+        let df = SessionContext::new().read_batch(batch).unwrap();
+        let describer = DataFrameDescriber::try_new(df).unwrap();
+
+        let result = describer
+            .column_correlation_with_target("target")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        let names = result[0]
+            .column(0)
+            .as_any()
+            .downcast_ref::<StringArray>()
+            .unwrap();
+        let values = result[0]
+            .column(1)
+            .as_any()
+            .downcast_ref::<Float64Array>()
+            .unwrap();
+        assert_eq!(
+            names.iter().flatten().collect::<Vec<_>>(),
+            vec!["same", "inverse", "noise"]
+        );
+        assert_approx_eq!(values.value(0), 1.0);
+        assert!(values.value(1) < -0.99);
+        assert_approx_eq!(values.value(2), 0.0);
+
+        assert!(describer
+            .column_correlation_with_target("name")
+            .await
+            .is_err());
+        assert!(describer
+            .column_correlation_with_target("missing")
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_describe_pandas_compatible() {
+        let describer = DataFrameDescriber::try_new(create_test_dataframe()).unwrap();
+        let result = describer
+            .describe_pandas_compatible()
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+
+        let labels = describe_labels(&result);
+        assert_eq!(
+            labels,
+            vec!["count", "mean", "std", "min", "25%", "50%", "75%", "max"]
+        );
+        let schema = result[0].schema();
+        let columns = schema.fields().iter().map(|f| f.name()).collect::<Vec<_>>();
+        assert_eq!(columns, vec!["describe", "float_col", "int_col"]);
+        assert_eq!(stat_value(&result, "count", "int_col"), "4.0");
+        assert_eq!(stat_value(&result, "mean", "float_col"), "2.5");
+    }
+
+    #[tokio::test]
+    async fn test_distinct_values() {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("category", DataType::Utf8, false),
+            Field::new("id", DataType::Int32, false),
+        ]));
+        let batch = RecordBatch::try_new(
+            schema,
+            vec![
+                Arc::new(StringArray::from(vec!["x", "y", "x"])) as ArrayRef,
+                Arc::new(Int32Array::from(vec![3, 1, 2])) as ArrayRef,
+            ],
+        )
+        .unwrap();
+        let df = SessionContext::new().read_batch(batch).unwrap();
+        let describer = DataFrameDescriber::try_new(df).unwrap();
+
+        let values = describer.distinct_values(10).await.unwrap();
+        assert_eq!(values["category"], vec!["x", "y"]);
+        assert_eq!(values["id"], vec!["1", "2", "3"]);
+
+        let values = describer.distinct_values(3).await.unwrap();
+        assert_eq!(values["category"], vec!["x", "y"]);
+        assert!(!values.contains_key("id"));
+    }
+
+    #[tokio::test]
+    async fn test_try_new_weighted() {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("x", DataType::Float64, false),
+            Field::new("w", DataType::Int32, false),
+        ]));
+        let batch = RecordBatch::try_new(
+            schema,
+            vec![
+                Arc::new(Float64Array::from(vec![1.0, 4.0])) as ArrayRef,
+                Arc::new(Int32Array::from(vec![10, 1])) as ArrayRef,
+            ],
+        )
+        .unwrap();
+        let df = SessionContext::new().read_batch(batch).unwrap();
+        let describer = DataFrameDescriber::try_new_weighted(df, "w").unwrap();
+
+        let stats = describer.describe_map().await.unwrap();
+        assert!(!stats.contains_key("w"));
+        let x = &stats["x"];
+        // frequency weights: as if 1.0 appeared ten times and 4.0 once
+        assert_approx_eq!(x["mean"].unwrap(), 14.0 / 11.0);
+        assert_approx_eq!(x["stddev"].unwrap(), (0.9_f64 * 10.0 / 11.0).sqrt());
+        assert_approx_eq!(x["median"].unwrap(), 1.0);
+        assert_eq!(x["total"], Some(2.0));
+        assert_eq!(x["max"], Some(4.0));
+
+        let result = describer.describe().await.unwrap().collect().await.unwrap();
+        assert!(result[0].column_by_name("w").is_none());
+        assert!(result[0].column_by_name(WEIGHT_COLUMN).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_describe_as_arrow_schema() {
+        let describer = DataFrameDescriber::try_new(create_test_dataframe())
+            .unwrap()
+            .with_methods(vec![
+                DescribeMethod::Total,
+                DescribeMethod::Mean,
+                DescribeMethod::IsConstant,
+            ]);
+        let schema = describer.describe_as_arrow_schema().unwrap();
+
+        let result = describer.describe().await.unwrap().collect().await.unwrap();
+        assert_eq!(&schema, result[0].schema().as_ref());
+        assert_eq!(
+            schema.field_with_name("float_col").unwrap().data_type(),
+            &DataType::Utf8
+        );
+        assert_eq!(
+            schema.field_with_name("describe").unwrap().data_type(),
+            &DataType::Utf8
+        );
+    }
+
+    #[tokio::test]
+    async fn test_describe_min_abs_max_abs() {
+        let schema = Arc::new(Schema::new(vec![Field::new("x", DataType::Float64, false)]));
+        let batch = RecordBatch::try_new(
+            schema,
+            vec![Arc::new(Float64Array::from(vec![-5.0, -1.0, 2.0])) as ArrayRef],
+        )
+        .unwrap();
+        let df = SessionContext::new().read_batch(batch).unwrap();
+        let describer = DataFrameDescriber::try_new(df)
+            .unwrap()
+            .with_methods(vec![DescribeMethod::MinAbs, DescribeMethod::MaxAbs]);
+
+        let result = describer.describe().await.unwrap().collect().await.unwrap();
+        assert_eq!(stat_value(&result, "min_abs", "x"), "1.0");
+        assert_eq!(stat_value(&result, "max_abs", "x"), "5.0");
+        assert!(matches!(
+            "max_abs".parse::<DescribeMethod>().unwrap(),
+            DescribeMethod::MaxAbs
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_try_from_sql() {
+        let ctx = SessionContext::new();
+        ctx.register_table("t", create_test_dataframe().into_view())
+            .unwrap();
+
+        let describer = DataFrameDescriber::try_from_sql(&ctx, "SELECT * FROM t WHERE int_col > 4")
+            .await
+            .unwrap()
+            .with_methods(vec![DescribeMethod::Total, DescribeMethod::Min]);
+        let result = describer.describe().await.unwrap().collect().await.unwrap();
+        assert_eq!(stat_value(&result, "total", "int_col"), "3.0");
+        assert_eq!(stat_value(&result, "min", "int_col"), "5.0");
+
+        let err = DataFrameDescriber::try_from_sql(&ctx, "SELECT * FROM missing")
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("SELECT * FROM missing"));
+    }
+
+    #[tokio::test]
+    async fn test_detect_multimodal_columns() {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("bimodal", DataType::Int32, false),
+            Field::new("unimodal", DataType::Float64, false),
+        ]));
+        let batch = RecordBatch::try_new(
+            schema,
+            vec![
+                Arc::new(Int32Array::from(vec![1, 1, 1, 10, 10, 10])) as ArrayRef,
+                Arc::new(Float64Array::from(vec![1.0, 2.0, 2.0, 2.5, 3.0, 4.0])) as ArrayRef,
+            ],
+        )
+        .unwrap();
+        let df = SessionContext::new().read_batch(batch).unwrap();
+        let describer = DataFrameDescriber::try_new(df).unwrap();
+
+        let columns = describer.detect_multimodal_columns(2).await.unwrap();
+        assert_eq!(columns, vec!["bimodal"]);
+        assert!(describer.detect_multimodal_columns(1).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_describe_with_schema_validation() {
+        let describer = DataFrameDescriber::try_new(create_test_dataframe()).unwrap();
+
+        let expected = Schema::new(vec![
+            Field::new("float_col", DataType::Float64, false),
+            Field::new("int_col", DataType::Int64, true),
+            Field::new("string_col", DataType::LargeUtf8, false),
+        ]);
+        let result = describer
+            .describe_with_schema_validation(&expected)
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(stat_value(&result, "mean", "int_col"), "5.5");
+
+        let expected = Schema::new(vec![
+            Field::new("float_col", DataType::Float64, false),
+            Field::new("int_col", DataType::Utf8, false),
+            Field::new("string_col", DataType::Utf8, false),
+        ]);
+        let err = describer
+            .describe_with_schema_validation(&expected)
+            .await
+            .unwrap_err();
+        let err = err.downcast_ref::<SchemaValidationError>().unwrap();
+        assert_eq!(err.column, "int_col");
+
+        let expected = Schema::new(vec![Field::new("float_col", DataType::Float64, false)]);
+        let err = describer
+            .describe_with_schema_validation(&expected)
+            .await
+            .unwrap_err();
+        let err = err.downcast_ref::<SchemaValidationError>().unwrap();
+        assert_eq!(err.column, "int_col");
+        assert_eq!(err.reason, "unexpected");
+    }
+
+    #[tokio::test]
+    async fn test_describe_orders_percentiles_numerically() {
+        let describer = DataFrameDescriber::try_new(create_test_dataframe())
+            .unwrap()
+            .with_methods(vec![
+                DescribeMethod::Percentile(100),
+                DescribeMethod::Max,
+                DescribeMethod::Percentile(5),
+                DescribeMethod::PercentileF(0.25),
+                DescribeMethod::Total,
+            ]);
+        let result = describer.describe().await.unwrap().collect().await.unwrap();
+
+        let labels = describe_labels(&result);
+        assert_eq!(
+            labels,
+            vec![
+                "total",
+                "max",
+                "percentile_5",
+                "percentile_0.25",
+                "percentile_100"
+            ]
+        );
+        assert!(
+            DescribeMethod::Percentile(25).sort_key() < DescribeMethod::Percentile(100).sort_key()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_describe_sample_rows() {
+        let describer = DataFrameDescriber::try_new(create_test_dataframe())
+            .unwrap()
+            .with_methods(vec![DescribeMethod::Total, DescribeMethod::Mean]);
+
+        let result = describer
+            .describe_sample_rows(2)
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(result.iter().map(|b| b.num_rows()).sum::<usize>(), 4);
+        assert_eq!(stat_value(&result, "mean", "int_col"), "5.5");
+        assert_eq!(stat_value(&result, "sample_0", "string_col"), "a");
+        assert_eq!(stat_value(&result, "sample_1", "float_col"), "2.0");
+
+        // fewer rows than requested
+        let result = describer
+            .describe_sample_rows(10)
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(result.iter().map(|b| b.num_rows()).sum::<usize>(), 6);
+        assert_eq!(stat_value(&result, "sample_3", "int_col"), "7");
+    }
+
+    #[tokio::test]
+    async fn test_count_regex_match() {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("code", DataType::Utf8, true),
+            Field::new("n", DataType::Int32, false),
+        ]));
+        let batch = RecordBatch::try_new(
+            schema,
+            vec![
+                Arc::new(StringArray::from(vec![
+                    Some("12"),
+                    Some("ab"),
+                    Some("34"),
+                    None,
+                ])) as ArrayRef,
+                Arc::new(Int32Array::from(vec![1, 2, 3, 4])) as ArrayRef,
+            ],
+        )
+        .unwrap();
+        let df = SessionContext::new().read_batch(batch).unwrap();
+        let describer = DataFrameDescriber::try_new(df).unwrap();
+
+        assert_eq!(
+            describer.count_regex_match("code", r"^\d+$").await.unwrap(),
+            2
+        );
+        assert!(describer.count_regex_match("code", "(").await.is_err());
+        assert!(describer.count_regex_match("n", r"^\d+$").await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_describe_fingerprint() {
+        let describer = DataFrameDescriber::try_new(create_test_dataframe()).unwrap();
+        let fingerprint = describer.describe_fingerprint().await.unwrap();
+        assert_eq!(describer.describe_fingerprint().await.unwrap(), fingerprint);
+
+        let other = DataFrameDescriber::try_new(create_test_dataframe()).unwrap();
+        assert_eq!(other.describe_fingerprint().await.unwrap(), fingerprint);
+
+        let batch = RecordBatch::try_new(
+            Arc::new(Schema::new(vec![
+                Field::new("float_col", DataType::Float64, false),
+                Field::new("int_col", DataType::Int32, false),
+                Field::new("string_col", DataType::Utf8, false),
+            ])),
+            vec![
+                Arc::new(Float64Array::from(vec![1.0, 2.0, 3.0, 5.0])) as ArrayRef,
+                Arc::new(Int32Array::from(vec![4, 5, 6, 7])) as ArrayRef,
+                Arc::new(StringArray::from(vec!["a", "b", "c", "d"])) as ArrayRef,
+            ],
+        )
+        .unwrap();
+        let changed =
+            DataFrameDescriber::try_new(SessionContext::new().read_batch(batch).unwrap()).unwrap();
+        assert_ne!(changed.describe_fingerprint().await.unwrap(), fingerprint);
+    }
+
+    #[tokio::test]
+    async fn test_describe_fingerprint_rounding() {
+        let fingerprint = |values: Vec<f64>, decimals: usize| async move {
+            let batch = RecordBatch::try_new(
+                Arc::new(Schema::new(vec![Field::new("x", DataType::Float64, false)])),
+                vec![Arc::new(Float64Array::from(values)) as ArrayRef],
+            )
+            .unwrap();
+            let df = SessionContext::new().read_batch(batch).unwrap();
+            DataFrameDescriber::try_new(df)
+                .unwrap()
+                .with_methods(vec![DescribeMethod::Mean])
+                .with_fingerprint_decimals(decimals)
+                .describe_fingerprint()
+                .await
+                .unwrap()
+        };
+        let noisy = vec![0.1, 0.2, 0.30000000001];
+        let exact = vec![0.1, 0.2, 0.3];
+        assert_eq!(
+            fingerprint(noisy.clone(), 6).await,
+            fingerprint(exact.clone(), 6).await
+        );
+        assert_ne!(fingerprint(noisy, 15).await, fingerprint(exact, 15).await);
+    }
+
+    #[tokio::test]
+    async fn test_describe_dictionary_columns() {
+        let names: DictionaryArray<Int32Type> =
+            vec!["ab", "abcd", "ab", "abc"].into_iter().collect();
+        let codes: DictionaryArray<Int8Type> = DictionaryArray::new(
+            Int8Array::from(vec![0, 1, 0, 1]),
+            Arc::new(Int32Array::from(vec![10, 30])),
+        );
+        let batch = RecordBatch::try_new(
+            Arc::new(Schema::new(vec![
+                Field::new("name", names.data_type().clone(), false),
+                Field::new("code", codes.data_type().clone(), false),
+            ])),
+            vec![Arc::new(names) as ArrayRef, Arc::new(codes) as ArrayRef],
+        )
+        .unwrap();
+        let df = SessionContext::new().read_batch(batch).unwrap();
+        let describer = DataFrameDescriber::try_new(df)
+            .unwrap()
+            .with_methods(vec![DescribeMethod::Mean, DescribeMethod::Max]);
+
+        let result = describer.describe().await.unwrap().collect().await.unwrap();
+        // string dictionaries are described by value length
+        assert_eq!(stat_value(&result, "mean", "name"), "2.75");
+        assert_eq!(stat_value(&result, "max", "name"), "4.0");
+        assert_eq!(stat_value(&result, "mean", "code"), "20.0");
+        assert_eq!(stat_value(&result, "max", "code"), "30.0");
+    }
+
+    #[tokio::test]
+    async fn test_completeness() {
+        let batch = RecordBatch::try_new(
+            Arc::new(Schema::new(vec![
+                Field::new("x", DataType::Int32, true),
+                Field::new("s", DataType::Utf8, false),
+            ])),
+            vec![
+                Arc::new(Int32Array::from(vec![Some(1), None, Some(3), Some(4)])) as ArrayRef,
+                Arc::new(StringArray::from(vec!["a", "b", "c", "d"])) as ArrayRef,
+            ],
+        )
+        .unwrap();
+        let df = SessionContext::new().read_batch(batch).unwrap();
+        let describer = DataFrameDescriber::try_new(df.clone()).unwrap();
+
+        let scores = describer.completeness().await.unwrap();
+        assert_approx_eq!(scores["x"], 0.75);
+        assert_approx_eq!(scores["s"], 1.0);
+
+        let empty = DataFrameDescriber::try_new(df.limit(0, Some(0)).unwrap()).unwrap();
+        let scores = empty.completeness().await.unwrap();
+        assert_eq!(scores["x"], 0.0);
+    }
+
+    #[tokio::test]
+    async fn test_describe_logical() {
+        let describer = DataFrameDescriber::try_new(create_test_dataframe())
+            .unwrap()
+            .with_methods(vec![DescribeMethod::Total, DescribeMethod::Mean]);
+
+        // compose the unevaluated describe output into a larger query
+        let result = describer
+            .describe_logical()
+            .unwrap()
+            .filter(col("describe").eq(lit("mean")))
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(result.iter().map(|b| b.num_rows()).sum::<usize>(), 1);
+        assert_eq!(stat_value(&result, "mean", "float_col"), "2.5");
+    }
+
+    #[tokio::test]
+    async fn test_detect_near_duplicate_columns() {
+        let batch = RecordBatch::try_new(
+            Arc::new(Schema::new(vec![
+                Field::new("a", DataType::Float64, false),
+                Field::new("a_noisy", DataType::Float64, false),
+                Field::new("other", DataType::Int32, false),
+            ])),
+            vec![
+                Arc::new(Float64Array::from(vec![1.0, 2.0, 3.0, 4.0, 5.0])) as ArrayRef,
+                Arc::new(Float64Array::from(vec![1.001, 1.999, 3.002, 3.998, 5.001])) as ArrayRef,
+                Arc::new(Int32Array::from(vec![3, 1, 4, 1, 5])) as ArrayRef,
+            ],
+        )
+        .unwrap();
+        let df = SessionContext::new().read_batch(batch).unwrap();
+        let describer = DataFrameDescriber::try_new(df).unwrap();
+
+        let pairs = describer.detect_near_duplicate_columns(0.99).await.unwrap();
+        assert_eq!(pairs.len(), 1);
+        let (a, b, r) = &pairs[0];
+        assert_eq!((a.as_str(), b.as_str()), ("a", "a_noisy"));
+        assert!(*r > 0.99);
+    }
+
+    #[tokio::test]
+    async fn test_describe_by_partition() {
+        let batch = RecordBatch::try_new(
+            Arc::new(Schema::new(vec![
+                Field::new("region", DataType::Utf8, false),
+                Field::new("x", DataType::Int32, false),
+            ])),
+            vec![
+                Arc::new(StringArray::from(vec!["eu", "us", "eu", "us", "us"])) as ArrayRef,
+                Arc::new(Int32Array::from(vec![1, 10, 3, 20, 30])) as ArrayRef,
+            ],
+        )
+        .unwrap();
+        let df = SessionContext::new().read_batch(batch).unwrap();
+        let describer = DataFrameDescriber::try_new(df)
+            .unwrap()
+            .with_methods(vec![DescribeMethod::Total, DescribeMethod::Mean]);
+
+        let result = describer
+            .describe_by_partition(&["region"])
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        let batch = concat_batches(&result[0].schema(), &result).unwrap();
+        let rows = (0..batch.num_rows())
+            .map(|i| {
+                ["region", "describe", "x"]
+                    .map(|c| array_value_to_string(batch.column_by_name(c).unwrap(), i).unwrap())
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            rows,
+            vec![
+                ["eu", "total", "2.0"],
+                ["eu", "mean", "2.0"],
+                ["us", "total", "3.0"],
+                ["us", "mean", "20.0"],
+            ]
+        );
+        assert!(describer.describe_by_partition(&["missing"]).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_describe_struct() {
+        let describer = DataFrameDescriber::try_new(create_test_dataframe())
+            .unwrap()
+            .with_methods(vec![
+                DescribeMethod::Total,
+                DescribeMethod::Mean,
+                DescribeMethod::Min,
+                DescribeMethod::IsConstant,
+            ]);
+        let result = describer
+            .describe_struct()
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(result[0].num_rows(), 1);
+
+        let stats = result[0]
+            .column_by_name("int_col")
+            .unwrap()
+            .as_any()
+            .downcast_ref::<StructArray>()
+            .unwrap();
+        assert_eq!(stats.column_names(), vec!["total", "mean", "min"]);
+        assert_eq!(
+            stats.column_by_name("min").unwrap().data_type(),
+            &DataType::Int32
+        );
+        assert_eq!(
+            stats.column_by_name("total").unwrap().data_type(),
+            &DataType::Int64
+        );
+        let mean = stats.column_by_name("mean").unwrap();
+        assert_eq!(array_value_to_string(mean, 0).unwrap(), "5.5");
+
+        let stats = result[0].column_by_name("string_col").unwrap();
+        let DataType::Struct(fields) = stats.data_type() else {
+            panic!("expected a struct column");
+        };
+        assert_eq!(fields.find("min").unwrap().1.data_type(), &DataType::Utf8);
+    }
+
+    #[tokio::test]
+    async fn test_value_counts() {
+        let batch = RecordBatch::try_new(
+            Arc::new(Schema::new(vec![Field::new("c", DataType::Utf8, true)])),
+            vec![Arc::new(StringArray::from(vec![
+                Some("x"),
+                Some("y"),
+                Some("x"),
+                None,
+                Some("z"),
+                Some("x"),
+                Some("y"),
+            ])) as ArrayRef],
+        )
+        .unwrap();
+        let df = SessionContext::new().read_batch(batch).unwrap();
+        let describer = DataFrameDescriber::try_new(df).unwrap();
+
+        let result = describer
+            .value_counts("c")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        let batch = concat_batches(&result[0].schema(), &result).unwrap();
+        let rows = (0..batch.num_rows())
+            .map(|i| {
+                let value = array_value_to_string(batch.column(0), i).unwrap();
+                let count = array_value_to_string(batch.column(1), i).unwrap();
+                (value, count)
+            })
+            .collect::<Vec<_>>();
+        let expected = [("x", "3"), ("y", "2"), ("z", "1"), ("", "1")];
+        assert_eq!(
+            rows,
+            expected
+                .map(|(v, c)| (v.to_string(), c.to_string()))
+                .to_vec()
+        );
+        assert!(describer.value_counts("missing").await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_percentile_range_check() {
+        // P90 / P10 of [1, 1, ..., 1, 5, ..., 5] (50 each) is exactly 5
+        let values = [1.0; 50].into_iter().chain([5.0; 50]).collect::<Vec<_>>();
+        let batch = RecordBatch::try_new(
+            Arc::new(Schema::new(vec![Field::new(
+                "salary",
+                DataType::Float64,
+                false,
+            )])),
+            vec![Arc::new(Float64Array::from(values)) as ArrayRef],
+        )
+        .unwrap();
+        let df = SessionContext::new().read_batch(batch).unwrap();
+        let describer = DataFrameDescriber::try_new(df).unwrap();
+
+        assert!(describer
+            .percentile_range_check("salary", 10, 90, 5.0, 0.01)
+            .await
+            .unwrap());
+        assert!(!describer
+            .percentile_range_check("salary", 10, 90, 3.0, 0.5)
+            .await
+            .unwrap());
+        assert!(describer
+            .percentile_range_check("missing", 10, 90, 5.0, 0.01)
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_with_min_count() {
+        let batch = RecordBatch::try_new(
+            Arc::new(Schema::new(vec![
+                Field::new("sparse", DataType::Float64, true),
+                Field::new("dense", DataType::Float64, false),
+            ])),
+            vec![
+                Arc::new(Float64Array::from(vec![Some(3.0), None, None])) as ArrayRef,
+                Arc::new(Float64Array::from(vec![1.0, 2.0, 3.0])) as ArrayRef,
+            ],
+        )
+        .unwrap();
+        let df = SessionContext::new().read_batch(batch).unwrap();
+        let describer = DataFrameDescriber::try_new(df)
+            .unwrap()
+            .with_methods(vec![
+                DescribeMethod::Total,
+                DescribeMethod::Mean,
+                DescribeMethod::Stddev,
+            ])
+            .with_min_count(2);
+
+        let stats = describer.describe_map().await.unwrap();
+        assert_eq!(stats["sparse"]["total"], Some(1.0));
+        assert_eq!(stats["sparse"]["mean"], None);
+        assert_eq!(stats["sparse"]["stddev"], None);
+        assert_eq!(stats["dense"]["total"], Some(3.0));
+        assert_eq!(stats["dense"]["mean"], Some(2.0));
+        assert_eq!(stats["dense"]["stddev"], Some(1.0));
+    }
+
+    #[tokio::test]
+    async fn test_describe_uint64_above_i64_max() {
+        let big = u64::MAX - 1;
+        let batch = RecordBatch::try_new(
+            Arc::new(Schema::new(vec![Field::new("u", DataType::UInt64, false)])),
+            vec![Arc::new(UInt64Array::from(vec![big, big, 1])) as ArrayRef],
+        )
+        .unwrap();
+        let df = SessionContext::new().read_batch(batch).unwrap();
+        let describer = DataFrameDescriber::try_new(df).unwrap().with_methods(vec![
+            DescribeMethod::Total,
+            DescribeMethod::Mean,
+            DescribeMethod::Min,
+            DescribeMethod::Max,
+            DescribeMethod::Median,
+        ]);
+
+        // statistics are widened to Float64, never wrapped through i64
+        let stats = &describer.describe_map().await.unwrap()["u"];
+        assert_eq!(stats["total"], Some(3.0));
+        assert_eq!(stats["min"], Some(1.0));
+        assert_eq!(stats["max"], Some(big as f64));
+        assert_eq!(stats["median"], Some(big as f64));
+        assert_approx_eq!(stats["mean"].unwrap() / (big as f64), 2.0 / 3.0);
+    }
+
+    #[tokio::test]
+    async fn test_auto_bin_continuous() {
+        // uniform over 1..=16: Sturges' rule gives ceil(log2(16) + 1) = 5 bins of width 3
+        let batch = RecordBatch::try_new(
+            Arc::new(Schema::new(vec![Field::new("x", DataType::Int32, false)])),
+            vec![Arc::new(Int32Array::from_iter_values(1..=16)) as ArrayRef],
+        )
+        .unwrap();
+        let df = SessionContext::new().read_batch(batch).unwrap();
+        let describer = DataFrameDescriber::try_new(df).unwrap();
+
+        let result = describer
+            .auto_bin_continuous("x")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        let batch = &result[0];
+        let column = |name: &str| {
+            let array =
+                cast_array(batch.column_by_name(name).unwrap(), &DataType::Float64).unwrap();
+            array
+                .as_any()
+                .downcast_ref::<Float64Array>()
+                .unwrap()
+                .values()
+                .to_vec()
+        };
+        assert_eq!(column("bin_lower"), vec![1.0, 4.0, 7.0, 10.0, 13.0]);
+        assert_eq!(column("bin_upper"), vec![4.0, 7.0, 10.0, 13.0, 16.0]);
+        assert_eq!(column("count"), vec![3.0, 3.0, 3.0, 3.0, 4.0]);
+        assert_approx_eq!(column("frequency").iter().sum::<f64>(), 1.0);
+
+        assert!(describer.auto_bin_continuous("missing").await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_describe_lazy() {
+        let describer = DataFrameDescriber::try_new(create_test_dataframe()).unwrap();
+
+        let result = describer
+            .describe_lazy()
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(stat_value(&result, "mean", "float_col"), "2.5");
+
+        // losing the race drops (cancels) the describe future
+        let cancelled = tokio::select! {
+            biased;
+            _ = std::future::ready(()) => true,
+            _ = describer.describe_lazy() => false,
+        };
+        assert!(cancelled);
+    }
+
+    #[tokio::test]
+    async fn test_describe_raw() {
+        let describer = DataFrameDescriber::try_new(create_test_dataframe())
+            .unwrap()
+            .with_methods(vec![DescribeMethod::Max, DescribeMethod::Total]);
+
+        let result = describer.describe_raw().unwrap().collect().await.unwrap();
+        let labels = describe_labels(&result);
+        // not sorted: `describe` would put total first
+        assert_eq!(labels, vec!["max", "total"]);
+        assert_eq!(stat_value(&result, "max", "int_col"), "7.0");
+    }
+
+    #[test]
+    fn test_schema_fingerprint() {
+        let fingerprint = |fields: Vec<Field>| {
+            let schema = Arc::new(Schema::new(fields));
+            let df = SessionContext::new()
+                .read_batch(RecordBatch::new_empty(schema))
+                .unwrap();
+            DataFrameDescriber::try_new(df)
+                .unwrap()
+                .schema_fingerprint()
+        };
+        let base = fingerprint(vec![
+            Field::new("a", DataType::Int32, false),
+            Field::new("b", DataType::Utf8, true),
+        ]);
+        assert_eq!(
+            base,
+            fingerprint(vec![
+                Field::new("a", DataType::Int32, false),
+                Field::new("b", DataType::Utf8, true),
+            ])
+        );
+        assert_ne!(
+            base,
+            fingerprint(vec![
+                Field::new("a", DataType::Int64, false),
+                Field::new("b", DataType::Utf8, true),
+            ])
+        );
+        assert_ne!(
+            base,
+            fingerprint(vec![
+                Field::new("a", DataType::Int32, true),
+                Field::new("b", DataType::Utf8, true),
+            ])
+        );
+        assert_ne!(
+            base,
+            fingerprint(vec![
+                Field::new("a", DataType::Int32, false),
+                Field::new("c", DataType::Utf8, true),
+            ])
+        );
+    }
+
+    #[tokio::test]
+    async fn test_describe_gini() {
+        let batch = RecordBatch::try_new(
+            Arc::new(Schema::new(vec![
+                Field::new("equal", DataType::Float64, false),
+                Field::new("unequal", DataType::Float64, false),
+                Field::new("negative", DataType::Float64, false),
+            ])),
+            vec![
+                Arc::new(Float64Array::from(vec![1.0, 1.0, 1.0])) as ArrayRef,
+                Arc::new(Float64Array::from(vec![0.0, 10.0, 0.0])) as ArrayRef,
+                Arc::new(Float64Array::from(vec![-1.0, 2.0, 3.0])) as ArrayRef,
+            ],
+        )
+        .unwrap();
+        let df = SessionContext::new().read_batch(batch).unwrap();
+        let describer = DataFrameDescriber::try_new(df)
+            .unwrap()
+            .with_methods(vec![DescribeMethod::Total, DescribeMethod::Gini]);
+
+        let stats = describer.describe_map().await.unwrap();
+        assert_approx_eq!(stats["equal"]["gini"].unwrap(), 0.0);
+        assert_approx_eq!(stats["unequal"]["gini"].unwrap(), 2.0 / 3.0);
+        assert_eq!(stats["negative"]["gini"], None);
+    }
+
+    #[tokio::test]
+    async fn test_median_strategy() {
+        let batch = RecordBatch::try_new(
+            Arc::new(Schema::new(vec![
+                Field::new("even", DataType::Int32, false),
+                Field::new("odd", DataType::Int32, true),
+            ])),
+            vec![
+                Arc::new(Int32Array::from(vec![4, 5, 6, 7])) as ArrayRef,
+                Arc::new(Int32Array::from(vec![Some(1), Some(9), Some(3), None])) as ArrayRef,
+            ],
+        )
+        .unwrap();
+        let df = SessionContext::new().read_batch(batch).unwrap();
+        let describer = DataFrameDescriber::try_new(df)
+            .unwrap()
+            .with_methods(vec![DescribeMethod::Median]);
+
+        for (strategy, even) in [
+            (MedianStrategy::Interpolate, 5.5),
+            (MedianStrategy::Lower, 5.0),
+            (MedianStrategy::Higher, 6.0),
+        ] {
+            let stats = describer
+                .derive(describer.original.clone())
+                .unwrap()
+                .with_median_strategy(strategy)
+                .describe_map()
+                .await
+                .unwrap();
+            assert_approx_eq!(stats["even"]["median"].unwrap(), even);
+            assert_approx_eq!(stats["odd"]["median"].unwrap(), 3.0);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_describe_gini_impurity() {
+        let batch = RecordBatch::try_new(
+            Arc::new(Schema::new(vec![
+                Field::new("equal", DataType::Int32, false),
+                Field::new("distinct", DataType::Int32, false),
+                Field::new("nullable", DataType::Int32, true),
+            ])),
+            vec![
+                Arc::new(Int32Array::from(vec![5, 5, 5, 5])) as ArrayRef,
+                Arc::new(Int32Array::from(vec![1, 2, 3, 4])) as ArrayRef,
+                Arc::new(Int32Array::from(vec![Some(1), Some(1), Some(2), None])) as ArrayRef,
+            ],
+        )
+        .unwrap();
+        let df = SessionContext::new().read_batch(batch).unwrap();
+        let describer = DataFrameDescriber::try_new(df)
+            .unwrap()
+            .with_methods(vec![DescribeMethod::GiniImpurity]);
+
+        let stats = describer.describe_map().await.unwrap();
+        assert_approx_eq!(stats["equal"]["gini_impurity"].unwrap(), 0.0);
+        assert_approx_eq!(stats["distinct"]["gini_impurity"].unwrap(), 0.75);
+        // nulls are not a value of their own
+        assert_approx_eq!(stats["nullable"]["gini_impurity"].unwrap(), 4.0 / 9.0);
+    }
+
+    #[tokio::test]
+    async fn test_with_filter() {
+        let describer = DataFrameDescriber::try_new(create_test_dataframe())
+            .unwrap()
+            .with_methods(vec![
+                DescribeMethod::Total,
+                DescribeMethod::Min,
+                DescribeMethod::IsConstant,
+            ])
+            .with_filter(col("int_col").gt(lit(5)));
+
+        let result = describer.describe().await.unwrap().collect().await.unwrap();
+        assert_eq!(stat_value(&result, "total", "int_col"), "2.0");
+        assert_eq!(stat_value(&result, "min", "int_col"), "6.0");
+        assert_eq!(stat_value(&result, "min", "float_col"), "3.0");
+        assert_eq!(stat_value(&result, "total", "string_col"), "2.0");
+        assert_eq!(stat_value(&result, "is_constant", "string_col"), "false");
+
+        // the original data is left untouched
+        assert_eq!(describer.original.clone().count().await.unwrap(), 4);
+    }
+
+    #[tokio::test]
+    async fn test_custom_cast() {
+        let cast_fn: CastFn =
+            Arc::new(|field: &Field| length(cast(ident(field.name()), DataType::Utf8)));
+        let describer =
+            DataFrameDescriber::try_new_with_custom_cast(create_test_dataframe(), cast_fn)
+                .unwrap()
+                .with_methods(vec![DescribeMethod::Min, DescribeMethod::Max]);
+
+        let result = describer.describe().await.unwrap().collect().await.unwrap();
+        // every column is described by the length of its string representation
+        assert_eq!(stat_value(&result, "min", "float_col"), "3.0");
+        assert_eq!(stat_value(&result, "max", "float_col"), "3.0");
+        assert_eq!(stat_value(&result, "min", "int_col"), "1.0");
+        assert_eq!(stat_value(&result, "max", "int_col"), "1.0");
+        assert_eq!(stat_value(&result, "max", "string_col"), "1.0");
+    }
+
+    #[tokio::test]
+    async fn test_group_by_then_describe() {
+        let batch = RecordBatch::try_new(
+            Arc::new(Schema::new(vec![
+                Field::new("region", DataType::Utf8, true),
+                Field::new("x", DataType::Int32, false),
+                Field::new("name", DataType::Utf8, false),
+            ])),
+            vec![
+                Arc::new(StringArray::from(vec![
+                    Some("eu"),
+                    Some("us"),
+                    None,
+                    Some("us"),
+                ])) as ArrayRef,
+                Arc::new(Int32Array::from(vec![1, 10, 3, 20])) as ArrayRef,
+                Arc::new(StringArray::from(vec!["a", "bb", "ccc", "dddd"])) as ArrayRef,
+            ],
+        )
+        .unwrap();
+        let df = SessionContext::new().read_batch(batch).unwrap();
+        let describer = DataFrameDescriber::try_new(df)
+            .unwrap()
+            .with_methods(vec![DescribeMethod::Total, DescribeMethod::Max]);
+
+        let result = describer
+            .group_by_then_describe(&["region"], &["x", "name"])
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        let batch = concat_batches(&result[0].schema(), &result).unwrap();
+        let rows = (0..batch.num_rows())
+            .map(|i| {
+                ["group_key", "column_name", "stat_name", "value"]
+                    .map(|c| array_value_to_string(batch.column_by_name(c).unwrap(), i).unwrap())
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            rows,
+            vec![
+                ["eu", "x", "total", "1.0"],
+                ["eu", "x", "max", "1.0"],
+                ["eu", "name", "total", "1.0"],
+                ["eu", "name", "max", "1.0"],
+                ["null", "x", "total", "1.0"],
+                ["null", "x", "max", "3.0"],
+                ["null", "name", "total", "1.0"],
+                ["null", "name", "max", "3.0"],
+                ["us", "x", "total", "2.0"],
+                ["us", "x", "max", "20.0"],
+                ["us", "name", "total", "2.0"],
+                ["us", "name", "max", "4.0"],
+            ]
+        );
+
+        assert!(describer
+            .group_by_then_describe(&["region"], &["missing"])
+            .await
+            .is_err());
+        assert!(DataFrameDescriber::try_new(describer.original.clone())
+            .unwrap()
+            .with_methods(vec![DescribeMethod::IsConstant])
+            .group_by_then_describe(&["region"], &["x"])
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_describe_union() {
+        let methods = vec![DescribeMethod::Min, DescribeMethod::Max];
+        let train = DataFrameDescriber::try_new(create_test_dataframe())
+            .unwrap()
+            .with_methods(methods.clone());
+        let shifted = create_test_dataframe()
+            .select(vec![
+                (col("float_col") * lit(10.0)).alias("float_col"),
+                (col("int_col") + lit(100)).alias("int_col"),
+                col("string_col"),
+            ])
+            .unwrap();
+        let test = DataFrameDescriber::try_new(shifted)
+            .unwrap()
+            .with_methods(methods.clone());
+
+        let result = train
+            .describe_union(&test)
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        let batch = concat_batches(&result[0].schema(), &result).unwrap();
+        let rows = (0..batch.num_rows())
+            .map(|i| {
+                ["source", "describe", "float_col", "int_col"]
+                    .map(|c| array_value_to_string(batch.column_by_name(c).unwrap(), i).unwrap())
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            rows,
+            vec![
+                ["self", "min", "1.0", "4.0"],
+                ["self", "max", "4.0", "7.0"],
+                ["other", "min", "10.0", "104.0"],
+                ["other", "max", "40.0", "107.0"],
+            ]
+        );
+
+        let narrow = create_test_dataframe()
+            .select(vec![col("float_col")])
+            .unwrap();
+        let narrow = DataFrameDescriber::try_new(narrow)
+            .unwrap()
+            .with_methods(methods);
+        assert!(train.describe_union(&narrow).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_top_percentiles() {
+        let batch = RecordBatch::try_new(
+            Arc::new(Schema::new(vec![Field::new("x", DataType::Float64, false)])),
+            vec![Arc::new(Float64Array::from(
+                (1..=1000).map(f64::from).collect::<Vec<_>>(),
+            )) as ArrayRef],
+        )
+        .unwrap();
+        let df = SessionContext::new().read_batch(batch).unwrap();
+        let describer = DataFrameDescriber::try_new(df)
+            .unwrap()
+            .with_methods(vec![DescribeMethod::Percentile(99)])
+            .with_top_percentiles(&[1])
+            .unwrap();
+
+        let result = describer.describe().await.unwrap().collect().await.unwrap();
+        assert!(describe_labels(&result).contains(&"top_1".to_string()));
+        assert_eq!(
+            stat_value(&result, "top_1", "x"),
+            stat_value(&result, "percentile_99", "x")
+        );
+        assert_eq!(
+            "top_1".parse::<DescribeMethod>().unwrap().to_string(),
+            "top_1"
+        );
+        assert!(DataFrameDescriber::try_new(create_test_dataframe())
+            .unwrap()
+            .with_top_percentiles(&[101])
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_transpose() {
+        let batch = RecordBatch::try_new(
+            Arc::new(Schema::new(vec![
+                Field::new("describe", DataType::Utf8, false),
+                Field::new("a", DataType::Float64, true),
+                Field::new("b", DataType::Float64, true),
+                Field::new("c", DataType::Float64, true),
+            ])),
+            vec![
+                Arc::new(StringArray::from(vec!["total", "mean"])) as ArrayRef,
+                Arc::new(Float64Array::from(vec![4.0, 2.5])) as ArrayRef,
+                Arc::new(Float64Array::from(vec![Some(3.0), None])) as ArrayRef,
+                Arc::new(Float64Array::from(vec![2.0, 1.0])) as ArrayRef,
+            ],
+        )
+        .unwrap();
+        let long = SessionContext::new().read_batch(batch).unwrap();
+
+        let result = transpose(long).await.unwrap().collect().await.unwrap();
+        let batch = concat_batches(&result[0].schema(), &result).unwrap();
+        assert_eq!(batch.num_rows(), 3);
+        assert_eq!(
+            batch
+                .schema()
+                .fields()
+                .iter()
+                .map(|f| f.name().as_str())
+                .collect::<Vec<_>>(),
+            vec!["column", "total", "mean"]
+        );
+        assert_eq!(batch.column(1).data_type(), &DataType::Float64);
+        let rows = (0..batch.num_rows())
+            .map(|i| {
+                ["column", "total", "mean"]
+                    .map(|c| array_value_to_string(batch.column_by_name(c).unwrap(), i).unwrap())
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            rows,
+            vec![["a", "4.0", "2.5"], ["b", "3.0", ""], ["c", "2.0", "1.0"],]
+        );
+
+        let describer = DataFrameDescriber::try_new(create_test_dataframe())
+            .unwrap()
+            .with_methods(vec![DescribeMethod::Total, DescribeMethod::Max]);
+        let wide = describer
+            .describe_transposed()
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(wide.iter().map(|b| b.num_rows()).sum::<usize>(), 3);
+
+        let no_labels = create_test_dataframe();
+        assert!(transpose(no_labels).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_detect_data_type_mismatch() {
+        let mut prices = vec!["1.5"; 19];
+        prices.push("N/A");
+        let mut codes = vec!["7"; 17];
+        codes.extend(["a", "b", "c"]);
+        let batch = RecordBatch::try_new(
+            Arc::new(Schema::new(vec![
+                Field::new("price", DataType::Utf8, false),
+                Field::new("code", DataType::Utf8, false),
+                Field::new("n", DataType::Int32, false),
+            ])),
+            vec![
+                Arc::new(StringArray::from(prices)) as ArrayRef,
+                Arc::new(StringArray::from(codes)) as ArrayRef,
+                Arc::new(Int32Array::from((0..20).collect::<Vec<_>>())) as ArrayRef,
+            ],
+        )
+        .unwrap();
+        let df = SessionContext::new().read_batch(batch).unwrap();
+        let describer = DataFrameDescriber::try_new(df).unwrap();
+
+        // 95% of price parses, only 85% of code
+        assert_eq!(
+            describer.detect_data_type_mismatch().await.unwrap(),
+            vec![TypeMismatch {
+                column: "price".to_string(),
+                declared_type: DataType::Utf8,
+                inferred_type: DataType::Float64,
+            }]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_row_statistics() {
+        let batch = RecordBatch::try_new(
+            Arc::new(Schema::new(vec![
+                Field::new("a", DataType::Int32, true),
+                Field::new("b", DataType::Float64, true),
+                Field::new("s", DataType::Utf8, true),
+            ])),
+            vec![
+                Arc::new(Int32Array::from(vec![Some(1), None, None])) as ArrayRef,
+                Arc::new(Float64Array::from(vec![Some(2.0), Some(4.0), None])) as ArrayRef,
+                Arc::new(StringArray::from(vec![Some("x"), None, Some("z")])) as ArrayRef,
+            ],
+        )
+        .unwrap();
+        let df = SessionContext::new().read_batch(batch).unwrap();
+        let describer = DataFrameDescriber::try_new(df).unwrap();
+
+        let result = describer.row_statistics().unwrap().collect().await.unwrap();
+        let batch = concat_batches(&result[0].schema(), &result).unwrap();
+        assert_eq!(batch.num_columns(), 6);
+        let rows = (0..batch.num_rows())
+            .map(|i| {
+                ["row_null_count", "row_numeric_sum", "row_numeric_mean"]
+                    .map(|c| array_value_to_string(batch.column_by_name(c).unwrap(), i).unwrap())
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            rows,
+            vec![["0", "3.0", "1.5"], ["2", "4.0", "4.0"], ["2", "0.0", ""],]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_first_last_follow_input_ordering() {
+        let methods = vec![DescribeMethod::First, DescribeMethod::Last];
+        let sorted = create_test_dataframe()
+            .sort(vec![col("int_col").sort(false, true)])
+            .unwrap();
+        let describer = DataFrameDescriber::try_new(sorted)
+            .unwrap()
+            .with_methods(methods.clone());
+
+        let result = describer.describe().await.unwrap().collect().await.unwrap();
+        assert_eq!(stat_value(&result, "first", "int_col"), "7.0");
+        assert_eq!(stat_value(&result, "last", "int_col"), "4.0");
+        assert_eq!(stat_value(&result, "first", "float_col"), "4.0");
+        // strings are described by length
+        assert_eq!(stat_value(&result, "first", "string_col"), "1.0");
+
+        let ascending = create_test_dataframe()
+            .sort(vec![col("float_col").sort(true, true)])
+            .unwrap()
+            .filter(col("int_col").gt(lit(4)))
+            .unwrap();
+        let describer = DataFrameDescriber::try_new(ascending)
+            .unwrap()
+            .with_methods(methods);
+        let result = describer.describe().await.unwrap().collect().await.unwrap();
+        assert_eq!(stat_value(&result, "first", "int_col"), "5.0");
+        assert_eq!(stat_value(&result, "last", "int_col"), "7.0");
+    }
+
+    #[tokio::test]
+    async fn test_describe_contract() {
+        let describer = DataFrameDescriber::try_new(create_test_dataframe()).unwrap();
+
+        let contract = describer.describe_contract().await.unwrap();
+        let int_col = &contract["columns"]["int_col"];
+        assert_eq!(int_col["type"], "Int32");
+        assert_eq!(int_col["completeness"], 1.0);
+        assert_eq!(int_col["distinct_count"], 4);
+        assert_eq!(int_col["min"], 4);
+        assert_eq!(int_col["max"], 7);
+        assert_eq!(contract["columns"]["float_col"]["max"], 4.0);
+        assert_eq!(contract["columns"]["string_col"]["min"], "a");
+    }
+
+    #[tokio::test]
+    async fn test_export_to_great_expectations_json() {
+        let batch = RecordBatch::try_new(
+            Arc::new(Schema::new(vec![
+                Field::new("x", DataType::Float64, false),
+                Field::new("sparse", DataType::Utf8, true),
+            ])),
+            vec![
+                Arc::new(Float64Array::from(vec![1.0, 2.0, 3.0, 4.0])) as ArrayRef,
+                Arc::new(StringArray::from(vec![Some("a"), None, None, None])) as ArrayRef,
+            ],
+        )
+        .unwrap();
+        let df = SessionContext::new().read_batch(batch).unwrap();
+        let describer = DataFrameDescriber::try_new(df).unwrap();
+
+        let suite = describer.export_to_great_expectations_json().await.unwrap();
+        let suite: Value = serde_json::from_str(&suite).unwrap();
+        let expectations = suite["expectations"].as_array().unwrap();
+        assert_eq!(expectations.len(), 2);
+        assert_eq!(
+            expectations[0],
+            json!({
+                "expectation_type": "expect_column_values_to_not_be_null",
+                "kwargs": { "column": "x" },
+            })
+        );
+        let kwargs = &expectations[1]["kwargs"];
+        assert_eq!(
+            expectations[1]["expectation_type"],
+            "expect_column_mean_to_be_between"
+        );
+        let stddev = (5.0f64 / 3.0).sqrt();
+        assert_approx_eq!(kwargs["min_value"].as_f64().unwrap(), 2.5 - 2.0 * stddev);
+        assert_approx_eq!(kwargs["max_value"].as_f64().unwrap(), 2.5 + 2.0 * stddev);
+    }
+
+    #[tokio::test]
+    async fn test_describe_plan_dot() {
+        let describer = DataFrameDescriber::try_new(create_test_dataframe())
+            .unwrap()
+            .with_methods(vec![DescribeMethod::Total, DescribeMethod::Max]);
+
+        let dot = describer.describe_plan_dot().await.unwrap();
+        assert!(dot.starts_with("digraph describe {"));
+        assert!(dot.trim_end().ends_with('}'));
+        assert!(dot.contains("UnionExec"));
+        assert!(dot.matches("AggregateExec").count() >= 2);
+        // every node but the root feeds exactly one other
+        let nodes = dot.matches("[label=").count();
+        assert_eq!(dot.matches(" -> ").count(), nodes - 1);
+    }
+
+    #[tokio::test]
+    async fn test_monotonic_violations() {
+        let batch = RecordBatch::try_new(
+            Arc::new(Schema::new(vec![
+                Field::new("i", DataType::Int32, false),
+                Field::new("x", DataType::Int32, false),
+                Field::new("gaps", DataType::Int32, true),
+            ])),
+            vec![
+                Arc::new(Int32Array::from(vec![0, 1, 2, 3])) as ArrayRef,
+                Arc::new(Int32Array::from(vec![1, 2, 1, 3])) as ArrayRef,
+                Arc::new(Int32Array::from(vec![Some(5), None, Some(1), Some(0)])) as ArrayRef,
+            ],
+        )
+        .unwrap();
+        let df = SessionContext::new().read_batch(batch).unwrap();
+        let describer = DataFrameDescriber::try_new(df.clone())
+            .unwrap()
+            .with_methods(vec![DescribeMethod::MonotonicViolations]);
+
+        let stats = describer.describe_map().await.unwrap();
+        assert_eq!(stats["x"]["monotonic_violations"], Some(1.0));
+        // 5 -> null -> 1 is not compared, only 1 -> 0 counts
+        assert_eq!(stats["gaps"]["monotonic_violations"], Some(1.0));
+
+        // follows the input ordering
+        let reversed = df.sort(vec![col("i").sort(false, true)]).unwrap();
+        let describer = DataFrameDescriber::try_new(reversed)
+            .unwrap()
+            .with_methods(vec![DescribeMethod::MonotonicViolations]);
+        let stats = describer.describe_map().await.unwrap();
+        assert_eq!(stats["i"]["monotonic_violations"], Some(3.0));
+        assert_eq!(stats["x"]["monotonic_violations"], Some(2.0));
+    }
+
+    #[tokio::test]
+    async fn test_assert_no_nulls() {
+        let batch = RecordBatch::try_new(
+            Arc::new(Schema::new(vec![
+                Field::new("id", DataType::Int32, false),
+                Field::new("a", DataType::Float64, true),
+                Field::new("b", DataType::Utf8, true),
+            ])),
+            vec![
+                Arc::new(Int32Array::from(vec![1, 2, 3])) as ArrayRef,
+                Arc::new(Float64Array::from(vec![None, Some(1.0), None])) as ArrayRef,
+                Arc::new(StringArray::from(vec![Some("x"), None, Some("z")])) as ArrayRef,
+            ],
+        )
+        .unwrap();
+        let df = SessionContext::new().read_batch(batch).unwrap();
+        let describer = DataFrameDescriber::try_new(df).unwrap();
+
+        describer.assert_no_nulls(&["id"]).await.unwrap();
+        let err = describer.assert_no_nulls(&[]).await.unwrap_err();
+        let err = err.downcast_ref::<NullConstraintError>().unwrap();
+        assert_eq!(
+            err.columns,
+            vec![("a".to_string(), 2), ("b".to_string(), 1)]
+        );
+        assert!(describer.assert_no_nulls(&["missing"]).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_fallback_transform() {
+        let describer = DataFrameDescriber::try_new(create_test_dataframe())
+            .unwrap()
+            .with_methods(vec![
+                DescribeMethod::Total,
+                DescribeMethod::Mean,
+                DescribeMethod::Max,
+            ])
+            .with_fallback_transform(Arc::new(|_: &Field| lit(0)))
+            .unwrap();
+
+        let result = describer.describe().await.unwrap().collect().await.unwrap();
+        assert_eq!(stat_value(&result, "total", "string_col"), "4.0");
+        assert_eq!(stat_value(&result, "mean", "string_col"), "0.0");
+        assert_eq!(stat_value(&result, "max", "string_col"), "0.0");
+        // numeric columns keep the built-in transform
+        assert_eq!(stat_value(&result, "max", "int_col"), "7.0");
+    }
+
+    #[tokio::test]
+    async fn test_describe_both() {
+        let describer = DataFrameDescriber::try_new(create_test_dataframe())
+            .unwrap()
+            .with_methods(vec![DescribeMethod::Total, DescribeMethod::Mean]);
+
+        let (long, wide) = describer.describe_both().await.unwrap();
+        let long = long.collect().await.unwrap();
+        let wide = wide.collect().await.unwrap();
+        let wide = concat_batches(&wide[0].schema(), &wide).unwrap();
+        assert_eq!(describe_labels(&long), vec!["total", "mean"]);
+        assert_eq!(wide.num_rows(), 3);
+        for (row, column) in ["float_col", "int_col", "string_col"].iter().enumerate() {
+            assert_eq!(
+                array_value_to_string(wide.column_by_name("column").unwrap(), row).unwrap(),
+                *column
+            );
+            for label in ["total", "mean"] {
+                assert_eq!(
+                    array_value_to_string(wide.column_by_name(label).unwrap(), row).unwrap(),
+                    stat_value(&long, label, column)
+                );
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_describe_with_hooks() {
+        let describer = DataFrameDescriber::try_new(create_test_dataframe())
+            .unwrap()
+            .with_methods(vec![
+                DescribeMethod::Mean,
+                DescribeMethod::Total,
+                DescribeMethod::IsConstant,
+            ]);
+        let events = std::sync::Mutex::new(vec![]);
+
+        let result = describer
+            .describe_with_hooks(
+                |m| events.lock().unwrap().push(format!("start {}", m)),
+                |m, _| events.lock().unwrap().push(format!("end {}", m)),
+            )
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(
+            events.into_inner().unwrap(),
+            vec![
+                "start mean",
+                "end mean",
+                "start total",
+                "end total",
+                "start is_constant",
+                "end is_constant",
+            ]
+        );
+        let expected = describer.describe().await.unwrap().collect().await.unwrap();
+        assert_eq!(describe_labels(&result), describe_labels(&expected));
+        for column in ["float_col", "int_col", "string_col"] {
+            for label in ["total", "mean", "is_constant"] {
+                assert_eq!(
+                    stat_value(&result, label, column),
+                    stat_value(&expected, label, column)
+                );
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_describe_with_column_renaming() {
+        let describer = DataFrameDescriber::try_new(create_test_dataframe())
+            .unwrap()
+            .with_methods(vec![DescribeMethod::Total, DescribeMethod::Max]);
+        let renames = |pairs: &[(&str, &str)]| {
+            pairs
+                .iter()
+                .map(|(a, b)| (a.to_string(), b.to_string()))
+                .collect::<HashMap<_, _>>()
+        };
+
+        let result = describer
+            .describe_with_column_renaming(renames(&[
+                ("int_col", "Quantity"),
+                ("unknown", "ignored"),
+            ]))
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        let schema = result[0].schema();
+        let names = schema
+            .fields()
+            .iter()
+            .map(|f| f.name().as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            names,
+            vec!["describe", "float_col", "Quantity", "string_col"]
+        );
+        assert_eq!(stat_value(&result, "max", "Quantity"), "7.0");
+
+        for conflict in [
+            renames(&[("int_col", "describe")]),
+            renames(&[("int_col", "float_col")]),
+            renames(&[("describe", "stat")]),
+        ] {
+            assert!(describer
+                .describe_with_column_renaming(conflict)
+                .await
+                .is_err());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_sanitized_names() {
+        let batch = RecordBatch::try_new(
+            Arc::new(Schema::new(vec![
+                Field::new("Col A", DataType::Int32, false),
+                Field::new("b", DataType::Int32, false),
+            ])),
+            vec![
+                Arc::new(Int32Array::from(vec![1, 2])) as ArrayRef,
+                Arc::new(Int32Array::from(vec![3, 4])) as ArrayRef,
+            ],
+        )
+        .unwrap();
+        let df = SessionContext::new().read_batch(batch).unwrap();
+        let describer = DataFrameDescriber::try_new(df)
+            .unwrap()
+            .with_methods(vec![DescribeMethod::Max])
+            .with_sanitized_names(true)
+            .unwrap();
+
+        let result = describer.describe().await.unwrap().collect().await.unwrap();
+        assert_eq!(stat_value(&result, "max", "col_a"), "2.0");
+        assert_eq!(stat_value(&result, "max", "b"), "4.0");
+        assert_eq!(
+            describer.name_mapping(),
+            HashMap::from([("col_a".to_string(), "Col A".to_string())])
+        );
+
+        let batch = RecordBatch::try_new(
+            Arc::new(Schema::new(vec![
+                Field::new("Col A", DataType::Int32, false),
+                Field::new("col-a", DataType::Int32, false),
+            ])),
+            vec![
+                Arc::new(Int32Array::from(vec![1])) as ArrayRef,
+                Arc::new(Int32Array::from(vec![2])) as ArrayRef,
+            ],
+        )
+        .unwrap();
+        let df = SessionContext::new().read_batch(batch).unwrap();
+        assert!(DataFrameDescriber::try_new(df)
+            .unwrap()
+            .with_sanitized_names(true)
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_monotonicity_check() {
+        let batch = RecordBatch::try_new(
+            Arc::new(Schema::new(vec![
+                Field::new("increasing", DataType::Int32, true),
+                Field::new("mostly", DataType::Float64, false),
+            ])),
+            vec![
+                Arc::new(Int32Array::from(vec![
+                    Some(1),
+                    Some(2),
+                    None,
+                    Some(2),
+                    Some(5),
+                ])) as ArrayRef,
+                Arc::new(Float64Array::from(vec![1.0, 2.0, 1.5, 3.0, 4.0])) as ArrayRef,
+            ],
+        )
+        .unwrap();
+        let df = SessionContext::new().read_batch(batch).unwrap();
+        let describer = DataFrameDescriber::try_new(df).unwrap();
+
+        assert_eq!(
+            describer.monotonicity_check("increasing").await.unwrap(),
+            MonotonicityResult {
+                is_monotone_increasing: true,
+                is_monotone_decreasing: false,
+                violations: 0,
+            }
+        );
+        assert_eq!(
+            describer.monotonicity_check("mostly").await.unwrap(),
+            MonotonicityResult {
+                is_monotone_increasing: false,
+                is_monotone_decreasing: false,
+                violations: 1,
+            }
+        );
+        assert!(describer.monotonicity_check("missing").await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_generate_synthetic_data() {
+        let describer = DataFrameDescriber::try_new(create_test_dataframe()).unwrap();
+
+        let synthetic = describer.generate_synthetic_data(2000).await.unwrap();
+        let schema = synthetic.schema().as_arrow().clone();
+        assert_eq!(
+            schema
+                .fields()
+                .iter()
+                .map(|f| (f.name().as_str(), f.data_type().clone()))
+                .collect::<Vec<_>>(),
+            vec![
+                ("float_col", DataType::Float64),
+                ("int_col", DataType::Int32),
+                ("string_col", DataType::Utf8),
+            ]
+        );
+        let stats = DataFrameDescriber::try_new(synthetic)
+            .unwrap()
+            .with_methods(vec![
+                DescribeMethod::Total,
+                DescribeMethod::NullTotal,
+                DescribeMethod::Mean,
+            ])
+            .describe_map()
+            .await
+            .unwrap();
+        assert_eq!(stats["float_col"]["total"], Some(2000.0));
+        // float_col has mean 2.5 and stddev ~1.29, the sample mean is well within 0.2
+        assert!((stats["float_col"]["mean"].unwrap() - 2.5).abs() < 0.2);
+        assert!((stats["int_col"]["mean"].unwrap() - 5.5).abs() < 0.2);
+        assert_eq!(stats["string_col"]["null_total"], Some(2000.0));
+    }
+
+    #[tokio::test]
+    async fn test_describe_cache() {
+        let cache = DescribeCache::default();
+        let df = create_test_dataframe();
+        let describer = DataFrameDescriber::try_new_with_cache(df.clone(), cache.clone())
+            .unwrap()
+            .with_methods(vec![DescribeMethod::Max]);
+
+        let first = describer.describe().await.unwrap().collect().await.unwrap();
+        assert_eq!(stat_value(&first, "max", "int_col"), "7.0");
+        assert_eq!(cache.lock().unwrap().len(), 1);
+
+        // tamper with the entry to tell a cache hit from a recomputation
+        for entry in cache.lock().unwrap().values_mut() {
+            let batch = &entry.batches[0];
+            let mut columns = batch.columns().to_vec();
+            columns[2] = Arc::new(Float64Array::from(vec![42.0]));
+            entry.batches[0] = RecordBatch::try_new(batch.schema(), columns).unwrap();
+        }
+        let second = DataFrameDescriber::try_new_with_cache(df, cache.clone())
+            .unwrap()
+            .with_methods(vec![DescribeMethod::Max])
+            .describe()
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(stat_value(&second, "max", "int_col"), "42.0");
+
+        // other methods or data are separate entries
+        describer
+            .scoped(vec![DescribeMethod::Min])
+            .unwrap()
+            .describe()
+            .await
+            .unwrap();
+        DataFrameDescriber::try_new_with_cache(create_test_dataframe(), cache.clone())
+            .unwrap()
+            .with_methods(vec![DescribeMethod::Max])
+            .describe()
+            .await
+            .unwrap();
+        assert_eq!(cache.lock().unwrap().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_describe_cache_dropped_table() {
+        let cache = DescribeCache::default();
+        // each table is dropped before the next is built, so a new table may land
+        // at a freed address; it must still not be served the old table's stats
+        for i in 0..10 {
+            let batch = RecordBatch::try_new(
+                Arc::new(Schema::new(vec![Field::new("v", DataType::Int32, false)])),
+                vec![Arc::new(Int32Array::from(vec![i])) as ArrayRef],
+            )
+            .unwrap();
+            let df = SessionContext::new().read_batch(batch).unwrap();
+            let result = DataFrameDescriber::try_new_with_cache(df, cache.clone())
+                .unwrap()
+                .with_methods(vec![DescribeMethod::Max])
+                .describe()
+                .await
+                .unwrap()
+                .collect()
+                .await
+                .unwrap();
+            assert_eq!(stat_value(&result, "max", "v"), format!("{}.0", i));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_describe_with_metadata() {
+        let describer = DataFrameDescriber::try_new(create_test_dataframe())
+            .unwrap()
+            .with_methods(vec![DescribeMethod::Total, DescribeMethod::Max]);
+
+        let before = Utc::now();
+        let (df, metadata) = describer.describe_with_metadata().await.unwrap();
+        let result = df.collect().await.unwrap();
+        assert_eq!(stat_value(&result, "max", "int_col"), "7.0");
+        assert!(metadata.computed_at >= before && metadata.computed_at <= Utc::now());
+        assert_eq!(metadata.original_row_count, 4);
+        assert_eq!(metadata.original_column_count, 3);
+        assert_eq!(
+            metadata
+                .methods
+                .iter()
+                .map(|m| m.to_string())
+                .collect::<Vec<_>>(),
+            vec!["total", "max"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_top_correlations() {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("x", DataType::Float64, false),
+            Field::new("noise", DataType::Float64, false),
+            Field::new("y", DataType::Int32, false),
+        ]));
+        let batch = RecordBatch::try_new(
+            schema,
+            vec![
+                Arc::new(Float64Array::from(vec![1.0, 2.0, 3.0, 4.0])) as ArrayRef,
+                Arc::new(Float64Array::from(vec![1.0, -1.0, -1.0, 1.0])) as ArrayRef,
+                Arc::new(Int32Array::from(vec![-2, -4, -6, -9])) as ArrayRef,
+            ],
+        )
+        .unwrap();
+        let df = SessionContext::new().read_batch(batch).unwrap();
+        let describer = DataFrameDescriber::try_new(df).unwrap();
+
+        let top = describer.top_correlations(2).await.unwrap();
+        assert_eq!(top.len(), 2);
+        assert_eq!((top[0].0.as_str(), top[0].1.as_str()), ("x", "y"));
+        assert!(top[0].2 < -0.99);
+        assert!(top[1].2.abs() <= top[0].2.abs());
+        assert!(top.iter().all(|(a, b, _)| a != b));
+
+        assert_eq!(describer.top_correlations(10).await.unwrap().len(), 3);
+        assert!(describer.top_correlations(0).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_with_coerce_numeric_strings() {
+        let schema = Arc::new(Schema::new(vec![Field::new(
+            "amount",
+            DataType::Utf8,
+            false,
+        )]));
+        let batch = RecordBatch::try_new(
+            schema,
+            vec![Arc::new(StringArray::from(vec!["1.5", "2.5", "x"])) as ArrayRef],
+        )
+        .unwrap();
+        let df = SessionContext::new().read_batch(batch).unwrap();
+        let methods = vec![DescribeMethod::Mean, DescribeMethod::NullTotal];
+
+        let describer = DataFrameDescriber::try_new(df)
+            .unwrap()
+            .with_methods(methods)
+            .with_coerce_numeric_strings(true)
+            .unwrap();
+        let result = describer.describe().await.unwrap().collect().await.unwrap();
+        assert_eq!(stat_value(&result, "mean", "amount"), "2.0");
+        assert_eq!(stat_value(&result, "null_total", "amount"), "1.0");
+
+        let result = describer
+            .with_coerce_numeric_strings(false)
+            .unwrap()
+            .describe()
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(stat_value(&result, "mean", "amount"), "2.3333333333333335");
+    }
+
+    #[tokio::test]
+    async fn test_describe_method_labels_are_lowercase_and_sort_stably() {
+        let methods = vec![
+            DescribeMethod::MaxBytes,
+            DescribeMethod::MinBytes,
+            DescribeMethod::RangeUtilization,
+            DescribeMethod::HasMixedSign,
+            DescribeMethod::MonotonicViolations,
+            DescribeMethod::Last,
+            DescribeMethod::First,
+            DescribeMethod::GiniImpurity,
+            DescribeMethod::NonEmptyCount,
+            DescribeMethod::EmptyCount,
+            DescribeMethod::Gini,
+            DescribeMethod::MaxAbs,
+            DescribeMethod::MinAbs,
+            DescribeMethod::MaxDecimalPlaces,
+            DescribeMethod::Skewness,
+            DescribeMethod::WhitespacePaddedCount,
+            DescribeMethod::IsConstant,
+            DescribeMethod::TopPercentile(1),
+            DescribeMethod::PercentileF(0.9),
+            DescribeMethod::Percentile(25),
+            DescribeMethod::Median,
+            DescribeMethod::Max,
+            DescribeMethod::Min,
+            DescribeMethod::Stddev,
+            DescribeMethod::StddevPop,
+            DescribeMethod::StddevSample,
+            DescribeMethod::Mean,
+            DescribeMethod::NullTotal,
+            DescribeMethod::Total,
+        ];
+        for method in &methods {
+            let label = method.to_string();
+            assert!(
+                label
+                    .chars()
+                    .all(|c| matches!(c, 'a'..='z' | '0'..='9' | '_' | '.')),
+                "{}",
+                label
+            );
+            assert_eq!(label.parse::<DescribeMethod>().unwrap().to_string(), label);
+        }
+        assert!("Mean".parse::<DescribeMethod>().is_err());
+
+        let mut expected = methods.clone();
+        expected.sort_by_key(|m| m.sort_key());
+        let expected = expected.iter().map(|m| m.to_string()).collect::<Vec<_>>();
+        for order in [methods.clone(), methods.iter().rev().cloned().collect()] {
+            let result = DataFrameDescriber::try_new(create_test_dataframe())
+                .unwrap()
+                .with_methods(order)
+                .describe()
+                .await
+                .unwrap()
+                .collect()
+                .await
+                .unwrap();
+            assert_eq!(describe_labels(&result), expected);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_with_version_metadata() {
+        let describer = DataFrameDescriber::try_new(create_test_dataframe())
+            .unwrap()
+            .with_methods(vec![DescribeMethod::Mean])
+            .with_output_batch_size(1)
+            .unwrap();
+        let untagged = describer.describe().await.unwrap();
+        assert!(untagged
+            .schema()
+            .metadata()
+            .get(SCHEMA_VERSION_KEY)
+            .is_none());
+
+        let tagged = describer
+            .with_version_metadata(true)
+            .describe()
+            .await
+            .unwrap();
+        assert_eq!(
+            tagged
+                .schema()
+                .metadata()
+                .get(SCHEMA_VERSION_KEY)
+                .map(|v| v.as_str()),
+            Some(DataFrameDescriber::schema_version())
+        );
+        let result = tagged.collect().await.unwrap();
+        assert_eq!(
+            result[0]
+                .schema()
+                .metadata()
+                .get(SCHEMA_VERSION_KEY)
+                .map(|v| v.as_str()),
+            Some(DataFrameDescriber::schema_version())
+        );
+        assert_eq!(stat_value(&result, "mean", "float_col"), "2.5");
+    }
+
+    #[tokio::test]
+    async fn test_detect_outlier_columns() {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("extreme", DataType::Float64, false),
+            Field::new("steady", DataType::Int32, false),
+            Field::new("spike", DataType::Int64, false),
+            Field::new("name", DataType::Utf8, false),
+        ]));
+        let batch = RecordBatch::try_new(
+            schema,
+            vec![
+                Arc::new(Float64Array::from(vec![
+                    0.0, 0.0, 0.0, 0.0, -100.0, -100.0, 100.0, 100.0,
+                ])) as ArrayRef,
+                Arc::new(Int32Array::from(vec![5; 8])) as ArrayRef,
+                Arc::new(Int64Array::from(vec![1, 2, 3, 4, 5, 6, 7, 1000])) as ArrayRef,
+                Arc::new(StringArray::from(vec!["a"; 8])) as ArrayRef,
+            ],
+        )
+        .unwrap();
+        let df = SessionContext::new().read_batch(batch).unwrap();
+        let describer = DataFrameDescriber::try_new(df).unwrap();
+
+        // half of `extreme` is more than a standard deviation from its mean
+        let flagged = describer
+            .detect_outlier_columns(OutlierMethod::ZScore(1.0), 0.4)
+            .await
+            .unwrap();
+        assert_eq!(flagged, vec!["extreme"]);
+        assert!(describer
+            .detect_outlier_columns(OutlierMethod::ZScore(1.0), 0.5)
+            .await
+            .unwrap()
+            .is_empty());
+
+        let flagged = describer
+            .detect_outlier_columns(OutlierMethod::Iqr(1.5), 0.1)
+            .await
+            .unwrap();
+        assert_eq!(flagged, vec!["spike"]);
+
+        assert!(describer
+            .detect_outlier_columns(OutlierMethod::Iqr(1.5), 1.5)
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_correlation_significance() {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("x", DataType::Float64, false),
+            Field::new("noise", DataType::Float64, false),
+            Field::new("double", DataType::Int32, false),
+        ]));
+        let batch = RecordBatch::try_new(
+            schema,
+            vec![
+                Arc::new(Float64Array::from(vec![
+                    1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0,
+                ])) as ArrayRef,
+                Arc::new(Float64Array::from(vec![
+                    1.0, -1.0, -1.0, 1.0, 1.0, -1.0, -1.0, 1.0,
+                ])) as ArrayRef,
+                Arc::new(Int32Array::from(vec![2, 4, 6, 8, 10, 12, 14, 16])) as ArrayRef,
+            ],
+        )
+        .unwrap();
+        let df = SessionContext::new().read_batch(batch).unwrap();
+        let describer = DataFrameDescriber::try_new(df).unwrap();
+
+        let result = describer
+            .correlation_significance(0.05)
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        let column = |i: usize| result[0].column(i).clone();
+        let col_a = column(0);
+        let col_a = col_a.as_any().downcast_ref::<StringArray>().unwrap();
+        let col_b = column(1);
+        let col_b = col_b.as_any().downcast_ref::<StringArray>().unwrap();
+        let p_values = column(3);
+        let p_values = p_values.as_any().downcast_ref::<Float64Array>().unwrap();
+        let significant = column(4);
+        let significant = significant.as_any().downcast_ref::<BooleanArray>().unwrap();
+        let row = |a: &str, b: &str| {
+            (0..result[0].num_rows())
+                .find(|&i| col_a.value(i) == a && col_b.value(i) == b)
+                .unwrap()
+        };
+
+        assert_eq!(result[0].num_rows(), 3);
+        let independent = row("x", "noise");
+        assert!(p_values.value(independent) > 0.05);
+        assert!(!significant.value(independent));
+        let correlated = row("x", "double");
+        assert!(p_values.value(correlated) < 1e-6);
+        assert!(significant.value(correlated));
+
+        assert!(describer.correlation_significance(0.0).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_try_new_concat() {
+        let frame = |values: Vec<Option<i32>>, name: &str| {
+            let schema = Arc::new(Schema::new(vec![
+                Field::new("value", DataType::Int32, true),
+                Field::new(name, DataType::Utf8, false),
+            ]));
+            let names = vec!["a"; values.len()];
+            let batch = RecordBatch::try_new(
+                schema,
+                vec![
+                    Arc::new(Int32Array::from(values)) as ArrayRef,
+                    Arc::new(StringArray::from(names)) as ArrayRef,
+                ],
+            )
+            .unwrap();
+            SessionContext::new().read_batch(batch).unwrap()
+        };
+        let monday = frame(vec![Some(1), Some(2), None], "day");
+        let tuesday = frame(vec![Some(3), None], "day");
+
+        let result = DataFrameDescriber::try_new_concat(vec![monday.clone(), tuesday])
+            .unwrap()
+            .with_methods(vec![
+                DescribeMethod::Total,
+                DescribeMethod::NullTotal,
+                DescribeMethod::Max,
+            ])
+            .describe()
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(stat_value(&result, "total", "value"), "3.0");
+        assert_eq!(stat_value(&result, "null_total", "value"), "2.0");
+        assert_eq!(stat_value(&result, "total", "day"), "5.0");
+        assert_eq!(stat_value(&result, "max", "value"), "3.0");
+
+        let renamed = frame(vec![Some(1)], "weekday");
+        let err = DataFrameDescriber::try_new_concat(vec![monday.clone(), renamed])
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("weekday"), "{}", err);
+        assert!(DataFrameDescriber::try_new_concat(vec![]).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_serialize_config_round_trip() {
+        let methods = vec![
+            DescribeMethod::Total,
+            DescribeMethod::NullTotal,
+            DescribeMethod::Mean,
+            DescribeMethod::Stddev,
+            DescribeMethod::StddevSample,
+            DescribeMethod::StddevPop,
+            DescribeMethod::Min,
+            DescribeMethod::Max,
+            DescribeMethod::Median,
+            DescribeMethod::Percentile(25),
+            DescribeMethod::PercentileF(0.999),
+            DescribeMethod::TopPercentile(1),
+            DescribeMethod::IsConstant,
+            DescribeMethod::WhitespacePaddedCount,
+            DescribeMethod::Skewness,
+            DescribeMethod::MaxDecimalPlaces,
+            DescribeMethod::MinAbs,
+            DescribeMethod::MaxAbs,
+            DescribeMethod::Gini,
+            DescribeMethod::GiniImpurity,
+            DescribeMethod::EmptyCount,
+            DescribeMethod::NonEmptyCount,
+            DescribeMethod::First,
+            DescribeMethod::Last,
+            DescribeMethod::MonotonicViolations,
+            DescribeMethod::HasMixedSign,
+            DescribeMethod::RangeUtilization,
+            DescribeMethod::MinBytes,
+            DescribeMethod::MaxBytes,
+        ];
+        let describer = DataFrameDescriber::try_new(create_test_dataframe())
+            .unwrap()
+            .with_methods(methods.clone());
+        let config = describer.serialize_config();
+
+        let restored = DataFrameDescriber::from_config(&config, create_test_dataframe()).unwrap();
+        assert_eq!(restored.serialize_config(), config);
+        assert_eq!(
+            restored
+                .methods
+                .iter()
+                .map(|m| m.to_string())
+                .collect::<Vec<_>>(),
+            methods.iter().map(|m| m.to_string()).collect::<Vec<_>>()
+        );
+
+        for invalid in [
+            "not json",
+            r#"{"columns": []}"#,
+            r#"{"methods": [1]}"#,
+            r#"{"methods": ["nope"]}"#,
+            // out of range parameters
+            r#"{"methods": ["top_150"]}"#,
+            r#"{"methods": ["percentile_1.5"]}"#,
+        ] {
+            assert!(DataFrameDescriber::from_config(invalid, create_test_dataframe()).is_err());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_binary_min_max_bytes() {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("key", DataType::Binary, true),
+            Field::new("large_key", DataType::LargeBinary, true),
+            Field::new("id", DataType::Int32, false),
+        ]));
+        let keys: Vec<Option<&[u8]>> =
+            vec![Some(b"\x01\xff"), Some(b"\x01"), None, Some(b"\xab\x00")];
+        let batch = RecordBatch::try_new(
+            schema,
+            vec![
+                Arc::new(BinaryArray::from(keys.clone())) as ArrayRef,
+                Arc::new(LargeBinaryArray::from(keys)) as ArrayRef,
+                Arc::new(Int32Array::from(vec![1, 2, 3, 4])) as ArrayRef,
+            ],
+        )
+        .unwrap();
+        let df = SessionContext::new().read_batch(batch).unwrap();
+
+        let result = DataFrameDescriber::try_new(df)
+            .unwrap()
+            .with_methods(vec![
+                DescribeMethod::Max,
+                DescribeMethod::MinBytes,
+                DescribeMethod::MaxBytes,
+            ])
+            .describe()
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        for column in ["key", "large_key"] {
+            assert_eq!(stat_value(&result, "min_bytes", column), "01");
+            assert_eq!(stat_value(&result, "max_bytes", column), "ab00");
+            // the length statistics are still computed
+            assert_eq!(stat_value(&result, "max", column), "2.0");
+        }
+        assert_eq!(stat_value(&result, "min_bytes", "id"), "");
+    }
+
+    #[tokio::test]
+    async fn test_with_max_columns() {
+        let err = DataFrameDescriber::try_new(create_test_dataframe())
+            .unwrap()
+            .with_max_columns(2)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("3 columns"), "{}", err);
+        assert!(err.contains("select_columns"), "{}", err);
+
+        let subset = create_test_dataframe()
+            .select_columns(&["float_col", "int_col"])
+            .unwrap();
+        let result = DataFrameDescriber::try_new(subset)
+            .unwrap()
+            .with_max_columns(2)
+            .unwrap()
+            .describe()
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(stat_value(&result, "mean", "float_col"), "2.5");
+    }
+
+    #[tokio::test]
+    async fn test_describe_chunked_output() {
+        use futures::TryStreamExt;
+
+        let describer = DataFrameDescriber::try_new(create_test_dataframe()).unwrap();
+        let chunks = describer
+            .describe_chunked_output(4)
+            .await
+            .unwrap()
+            .try_collect::<Vec<_>>()
+            .await
+            .unwrap();
+        // 10 default methods
+        assert_eq!(
+            chunks.iter().map(|b| b.num_rows()).collect::<Vec<_>>(),
+            vec![4, 4, 2]
+        );
+        assert_eq!(
+            describe_labels(&chunks),
+            describe_labels(&describer.describe().await.unwrap().collect().await.unwrap())
+        );
+        assert_eq!(stat_value(&chunks, "mean", "float_col"), "2.5");
+
+        assert!(describer.describe_chunked_output(0).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_detect_mixed_type_string_columns() {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("mixed", DataType::Utf8, true),
+            Field::new("empty", DataType::Utf8, true),
+        ]));
+        let batch = RecordBatch::try_new(
+            schema,
+            vec![
+                Arc::new(StringArray::from(vec![
+                    Some("123"),
+                    Some("4.5"),
+                    Some("abc"),
+                    Some("2024-01-01"),
+                    Some("true"),
+                    None,
+                ])) as ArrayRef,
+                Arc::new(StringArray::from(vec![None::<&str>; 6])) as ArrayRef,
+            ],
+        )
+        .unwrap();
+        let df = SessionContext::new().read_batch(batch).unwrap();
+        let describer = DataFrameDescriber::try_new(df).unwrap();
+
+        let report = describer
+            .detect_mixed_type_string_columns("mixed")
+            .await
+            .unwrap();
+        assert_approx_eq!(report.numeric_fraction, 0.4);
+        assert_approx_eq!(report.date_fraction, 0.2);
+        assert_approx_eq!(report.boolean_fraction, 0.2);
+        assert_approx_eq!(report.pure_string_fraction, 0.2);
+
+        let report = describer
+            .detect_mixed_type_string_columns("empty")
+            .await
+            .unwrap();
+        assert_eq!(report.pure_string_fraction, 0.0);
+
+        let df = create_test_dataframe();
+        let describer = DataFrameDescriber::try_new(df).unwrap();
+        assert!(describer
+            .detect_mixed_type_string_columns("int_col")
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_stddev_sample_and_pop() {
+        let describer = DataFrameDescriber::try_new(create_test_dataframe())
+            .unwrap()
+            .with_methods(vec![
+                DescribeMethod::StddevPop,
+                DescribeMethod::StddevSample,
+                DescribeMethod::Stddev,
+            ]);
+        let result = describer.describe().await.unwrap().collect().await.unwrap();
+        assert_eq!(
+            describe_labels(&result),
+            vec!["stddev", "stddev_sample", "stddev_pop"]
+        );
+
+        // [1.0, 2.0, 3.0, 4.0] has squared deviations summing to 5
+        let stats = describer.describe_map().await.unwrap();
+        let stat = &stats["float_col"];
+        assert_approx_eq!(stat["stddev_sample"].unwrap(), (5.0f64 / 3.0).sqrt());
+        assert_approx_eq!(stat["stddev_pop"].unwrap(), (5.0f64 / 4.0).sqrt());
+        assert_eq!(stat["stddev"], stat["stddev_sample"]);
+
+        let weights = DataFrameDescriber::try_new_weighted(create_test_dataframe(), "int_col")
+            .unwrap()
+            .with_methods(vec![
+                DescribeMethod::StddevSample,
+                DescribeMethod::StddevPop,
+            ])
+            .describe_map()
+            .await
+            .unwrap();
+        let stat = &weights["float_col"];
+        assert!(stat["stddev_pop"].unwrap() < stat["stddev_sample"].unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_with_dataset_summary() {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("value", DataType::Int32, true),
+            Field::new("name", DataType::Utf8, true),
+        ]));
+        let batch = RecordBatch::try_new(
+            schema,
+            vec![
+                Arc::new(Int32Array::from(vec![Some(1), None, Some(3), None])) as ArrayRef,
+                Arc::new(StringArray::from(vec![
+                    Some("a"),
+                    None,
+                    Some("c"),
+                    Some("d"),
+                ])) as ArrayRef,
+            ],
+        )
+        .unwrap();
+        let df = SessionContext::new().read_batch(batch).unwrap();
+        let describer = DataFrameDescriber::try_new(df)
+            .unwrap()
+            .with_methods(vec![DescribeMethod::Max, DescribeMethod::Total])
+            .with_dataset_summary(true);
+
+        let result = describer.describe().await.unwrap().collect().await.unwrap();
+        assert_eq!(
+            describe_labels(&result),
+            vec!["total", "max", "__dataset__"]
+        );
+        assert_eq!(stat_value(&result, "__dataset__", "__dataset_rows"), "4.0");
+        assert_eq!(
+            stat_value(&result, "__dataset__", "__dataset_columns"),
+            "2.0"
+        );
+        assert_eq!(stat_value(&result, "__dataset__", "__dataset_cells"), "8.0");
+        assert_eq!(
+            stat_value(&result, "__dataset__", "__dataset_null_percentage"),
+            "37.5"
+        );
+        assert_eq!(stat_value(&result, "__dataset__", "value"), "");
+        assert_eq!(stat_value(&result, "max", "value"), "3.0");
+        assert_eq!(stat_value(&result, "max", "__dataset_rows"), "");
+
+        let hooked = describer
+            .describe_with_hooks(|_| {}, |_, _| {})
+            .await
+            .unwrap();
+        let hooked = hooked.collect().await.unwrap();
+        assert_eq!(describe_labels(&hooked), describe_labels(&result));
+
+        let plain = describer
+            .with_dataset_summary(false)
+            .describe()
+            .await
+            .unwrap();
+        assert!(plain
+            .schema()
+            .field_with_unqualified_name("__dataset_rows")
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_kl_divergence() {
+        let describer = |values: Vec<f64>| {
+            let schema = Arc::new(Schema::new(vec![Field::new("x", DataType::Float64, true)]));
+            let batch = RecordBatch::try_new(
+                schema,
+                vec![Arc::new(Float64Array::from(values)) as ArrayRef],
+            )
+            .unwrap();
+            DataFrameDescriber::try_new(SessionContext::new().read_batch(batch).unwrap()).unwrap()
+        };
+        let values = (1..=20).map(|i| i as f64).collect::<Vec<_>>();
+        let current = describer(values.clone());
+        let same = describer(values);
+        let disjoint = describer((101..=120).map(|i| i as f64).collect());
+
+        let kl = current.kl_divergence(&same, "x", 10).await.unwrap();
+        assert_approx_eq!(kl, 0.0);
+        let kl = current.kl_divergence(&disjoint, "x", 10).await.unwrap();
+        assert!(kl > 1.0, "{}", kl);
+        assert!(kl.is_finite());
+
+        assert!(current.kl_divergence(&same, "x", 0).await.is_err());
+        assert!(current.kl_divergence(&same, "missing", 10).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_population_stability_index() {
+        let describer = |values: Vec<i32>| {
+            let schema = Arc::new(Schema::new(vec![Field::new("x", DataType::Int32, true)]));
+            let batch =
+                RecordBatch::try_new(schema, vec![Arc::new(Int32Array::from(values)) as ArrayRef])
+                    .unwrap();
+            DataFrameDescriber::try_new(SessionContext::new().read_batch(batch).unwrap()).unwrap()
+        };
+        let actual = describer(vec![0, 0, 0, 10]);
+        let expected = describer(vec![0, 10, 10, 10]);
+
+        let psi = actual
+            .population_stability_index(&actual, "x", 2)
+            .await
+            .unwrap();
+        assert_approx_eq!(psi, 0.0);
+        assert_eq!(PopulationShift::from_psi(psi), PopulationShift::None);
+
+        // smoothed bin frequencies are [4/6, 2/6] and [2/6, 4/6]
+        let psi = actual
+            .population_stability_index(&expected, "x", 2)
+            .await
+            .unwrap();
+        assert_approx_eq!(psi, 2.0 / 3.0 * 2f64.ln());
+        assert_eq!(PopulationShift::from_psi(psi), PopulationShift::Significant);
+        assert_eq!(PopulationShift::from_psi(0.2), PopulationShift::Moderate);
+    }
+
+    #[tokio::test]
+    async fn test_weighted_percentile_method() {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("value", DataType::Int32, true),
+            Field::new("weight", DataType::Float64, true),
+        ]));
+        let batch = RecordBatch::try_new(
+            schema,
+            vec![
+                Arc::new(Int32Array::from(vec![
+                    Some(1),
+                    Some(2),
+                    Some(3),
+                    None,
+                    Some(4),
+                ])) as ArrayRef,
+                Arc::new(Float64Array::from(vec![
+                    Some(1.0),
+                    Some(1.0),
+                    Some(10.0),
+                    Some(5.0),
+                    Some(0.0),
+                ])) as ArrayRef,
+            ],
+        )
+        .unwrap();
+        let df = SessionContext::new().read_batch(batch).unwrap();
+        let describer = DataFrameDescriber::try_new(df).unwrap();
+
+        // the unweighted median is 2, the weight of 3 pulls it up
+        let median = describer
+            .weighted_percentile("value", "weight", 0.5)
+            .await
+            .unwrap();
+        assert_eq!(median, 3.0);
+        let low = describer
+            .weighted_percentile("value", "weight", 0.1)
+            .await
+            .unwrap();
+        assert_eq!(low, 2.0);
+        let min = describer
+            .weighted_percentile("value", "weight", 0.0)
+            .await
+            .unwrap();
+        assert_eq!(min, 1.0);
+
+        assert!(describer
+            .weighted_percentile("value", "weight", 1.5)
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_apply_before_describe() {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("delta", DataType::Int32, false),
+            Field::new("name", DataType::Utf8, false),
+        ]));
+        let batch = RecordBatch::try_new(
+            schema,
+            vec![
+                Arc::new(Int32Array::from(vec![-5, 2, -1, 4])) as ArrayRef,
+                Arc::new(StringArray::from(vec!["a", "b", "b", "c"])) as ArrayRef,
+            ],
+        )
+        .unwrap();
+        let df = SessionContext::new().read_batch(batch).unwrap();
+        let clip: PreTransformFn = Arc::new(|df: DataFrame| {
+            let delta = ident("delta");
+            Ok(df.select(vec![
+                when(delta.clone().lt(lit(0)), lit(0))
+                    .otherwise(delta)?
+                    .alias("delta"),
+                ident("name"),
+            ])?)
+        });
+        let describer = DataFrameDescriber::try_new(df.clone())
+            .unwrap()
+            .with_methods(vec![DescribeMethod::Min, DescribeMethod::Mean])
+            .apply_before_describe(clip)
+            .unwrap();
+
+        let result = describer.describe().await.unwrap().collect().await.unwrap();
+        assert_eq!(stat_value(&result, "min", "delta"), "0.0");
+        assert_eq!(stat_value(&result, "mean", "delta"), "1.5");
+
+        // the original DataFrame is untouched
+        let original = DataFrameDescriber::try_new(df)
+            .unwrap()
+            .with_methods(vec![DescribeMethod::Min])
+            .describe()
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(stat_value(&original, "min", "delta"), "-5.0");
+
+        let failing: PreTransformFn = Arc::new(|_| anyhow::bail!("no"));
+        assert!(describer.apply_before_describe(failing).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_with_exact_temporal() {
+        // beyond 2^53 nanoseconds, so not every value is a Float64
+        let base = 1_700_000_000_123_456_789i64;
+        let values = vec![base + 3, base, base + 1];
+        let schema = Arc::new(Schema::new(vec![Field::new(
+            "ts",
+            DataType::Timestamp(TimeUnit::Nanosecond, None),
+            false,
+        )]));
+        let batch = RecordBatch::try_new(
+            schema,
+            vec![Arc::new(TimestampNanosecondArray::from(values)) as ArrayRef],
+        )
+        .unwrap();
+        let df = SessionContext::new().read_batch(batch).unwrap();
+        let methods = vec![
+            DescribeMethod::Min,
+            DescribeMethod::Max,
+            DescribeMethod::Median,
+        ];
+        let nanos = |result: &[RecordBatch], label: &str| {
+            let labels = describe_labels(result);
+            let row = labels.iter().position(|l| l == label).unwrap();
+            result[0]
+                .column_by_name("ts")
+                .unwrap()
+                .as_any()
+                .downcast_ref::<TimestampNanosecondArray>()
+                .unwrap()
+                .value(row)
+        };
+
+        let describer = DataFrameDescriber::try_new(df)
+            .unwrap()
+            .with_methods(methods)
+            .with_output_batch_size(10)
+            .unwrap();
+        let rounded = describer.describe().await.unwrap().collect().await.unwrap();
+        assert_ne!(nanos(&rounded, "max"), base + 3);
+
+        let exact = describer
+            .with_exact_temporal(true)
+            .unwrap()
+            .describe()
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(nanos(&exact, "min"), base);
+        assert_eq!(nanos(&exact, "max"), base + 3);
+        assert_eq!(nanos(&exact, "median"), base + 1);
+
+        // dates can't be cast to Float64, but are described exactly
+        let schema = Arc::new(Schema::new(vec![Field::new(
+            "day",
+            DataType::Date32,
+            false,
+        )]));
+        let batch = RecordBatch::try_new(
+            schema,
+            vec![Arc::new(Date32Array::from(vec![19_000, 19_002, 19_001])) as ArrayRef],
+        )
+        .unwrap();
+        let df = SessionContext::new().read_batch(batch).unwrap();
+        let result = DataFrameDescriber::try_new(df)
+            .unwrap()
+            .with_methods(vec![DescribeMethod::Max])
+            .with_exact_temporal(true)
+            .unwrap()
+            .describe()
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(stat_value(&result, "max", "day"), "2022-01-10");
+    }
+
+    #[cfg(feature = "deltalake")]
+    #[tokio::test]
+    async fn test_describe_to_delta_lake() {
+        let describer = DataFrameDescriber::try_new(create_test_dataframe()).unwrap();
+        let dir = std::env::temp_dir().join(format!("describe_delta_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.to_str().unwrap();
+
+        describer
+            .describe_to_delta_lake(path, SaveMode::Append)
+            .await
+            .unwrap();
+        describer
+            .describe_to_delta_lake(path, SaveMode::Append)
+            .await
+            .unwrap();
+        assert_eq!(
+            deltalake::open_table(path).await.unwrap().get_files_count(),
+            2
+        );
+
+        describer
+            .describe_to_delta_lake(path, SaveMode::Overwrite)
+            .await
+            .unwrap();
+        assert_eq!(
+            deltalake::open_table(path).await.unwrap().get_files_count(),
+            1
+        );
+
+        assert!(describer
+            .describe_to_delta_lake(path, SaveMode::ErrorIfExists)
+            .await
+            .is_err());
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    /// `create_test_dataframe` weighted by `int_col`, which is not described.
+    fn weighted_test_describer() -> DataFrameDescriber {
+        DataFrameDescriber::try_new_weighted(create_test_dataframe(), "int_col").unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_feature_importance_proxy_weighted() {
+        let result = weighted_test_describer()
+            .feature_importance_proxy()
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(result.iter().map(|b| b.num_rows()).sum::<usize>(), 1);
+        assert_eq!(
+            array_value_to_string(result[0].column_by_name("column_name").unwrap(), 0).unwrap(),
+            "float_col"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_detect_skewed_columns_weighted() {
+        let skewed = weighted_test_describer()
+            .detect_skewed_columns(0.0)
+            .await
+            .unwrap();
+        assert!(!skewed.contains(&"int_col".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_detect_multimodal_columns_weighted() {
+        let multimodal = weighted_test_describer()
+            .detect_multimodal_columns(2)
+            .await
+            .unwrap();
+        assert!(!multimodal.contains(&"int_col".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_auto_bin_continuous_weighted() {
+        let describer = weighted_test_describer();
+        let bins = describer
+            .auto_bin_continuous("float_col")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(bins.iter().map(|b| b.num_rows()).sum::<usize>(), 3);
+        assert!(describer.auto_bin_continuous("int_col").await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_percentile_range_check_weighted() {
+        let describer = weighted_test_describer();
+        assert!(describer
+            .percentile_range_check("float_col", 25, 75, 2.0, 2.0)
+            .await
+            .unwrap());
+        assert!(describer
+            .percentile_range_check("int_col", 25, 75, 2.0, 2.0)
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_confidence_interval_weighted() {
+        let describer = weighted_test_describer();
+        let (lower, upper) = describer
+            .confidence_interval("float_col", 0.95)
+            .await
+            .unwrap();
+        assert!(lower < upper);
+        assert!(describer
+            .confidence_interval("int_col", 0.95)
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_min_max_scale_weighted() {
+        let result = weighted_test_describer()
+            .min_max_scale()
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        let float_col = result[0]
+            .column_by_name("float_col")
+            .unwrap()
+            .as_any()
+            .downcast_ref::<Float64Array>()
+            .unwrap();
+        assert_eq!(
+            float_col.values().to_vec(),
+            vec![0.0, 1.0 / 3.0, 2.0 / 3.0, 1.0]
+        );
+        // the weight column is not scaled
+        let int_col = result[0]
+            .column_by_name("int_col")
+            .unwrap()
+            .as_any()
+            .downcast_ref::<Int32Array>()
+            .unwrap();
+        assert_eq!(int_col.values().to_vec(), vec![4, 5, 6, 7]);
+    }
+
+    #[tokio::test]
+    async fn test_helpers_after_dropping_hook() {
+        let drop_int: PreTransformFn =
+            Arc::new(|df: DataFrame| Ok(df.select(vec![ident("float_col"), ident("string_col")])?));
+        let describer = DataFrameDescriber::try_new(create_test_dataframe())
+            .unwrap()
+            .apply_before_describe(drop_int)
+            .unwrap();
+
+        let scaled = describer.min_max_scale().await.unwrap();
+        assert!(scaled
+            .schema()
+            .field_with_unqualified_name("int_col")
+            .is_err());
+        let result = scaled.collect().await.unwrap();
+        assert_eq!(result[0].num_columns(), 2);
+
+        let proxy = describer
+            .feature_importance_proxy()
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(proxy.iter().map(|b| b.num_rows()).sum::<usize>(), 1);
+        assert!(describer
+            .confidence_interval("int_col", 0.95)
+            .await
+            .is_err());
+        assert!(describer
+            .detect_outlier_columns(OutlierMethod::ZScore(3.0), 0.0)
+            .await
+            .unwrap()
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_describe_sample_rows_sanitized_names() {
+        let schema = Arc::new(Schema::new(vec![Field::new(
+            "Col A",
+            DataType::Int32,
+            false,
+        )]));
+        let batch = RecordBatch::try_new(
+            schema,
+            vec![Arc::new(Int32Array::from(vec![1, 2])) as ArrayRef],
+        )
+        .unwrap();
+        let df = SessionContext::new().read_batch(batch).unwrap();
+        let describer = DataFrameDescriber::try_new(df)
+            .unwrap()
+            .with_methods(vec![DescribeMethod::Max])
+            .with_sanitized_names(true)
+            .unwrap();
+
+        let result = describer
+            .describe_sample_rows(2)
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(stat_value(&result, "max", "col_a"), "2.0");
+        assert_eq!(stat_value(&result, "sample_0", "col_a"), "1");
+        assert_eq!(stat_value(&result, "sample_1", "col_a"), "2");
+    }
+
+    #[tokio::test]
+    async fn test_dataset_summary_per_column_outputs() {
+        let describer = DataFrameDescriber::try_new(create_test_dataframe())
+            .unwrap()
+            .with_methods(vec![DescribeMethod::Total, DescribeMethod::Max])
+            .with_dataset_summary(true);
+
+        let result = describer
+            .describe_sample_rows(1)
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(stat_value(&result, "__dataset__", "__dataset_rows"), "4.0");
+        assert_eq!(stat_value(&result, "sample_0", "__dataset_rows"), "");
+        assert_eq!(stat_value(&result, "sample_0", "int_col"), "4");
+
+        let wide = describer
+            .describe_transposed()
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(wide.iter().map(|b| b.num_rows()).sum::<usize>(), 3);
+        let names = wide[0]
+            .schema()
+            .fields()
+            .iter()
+            .map(|f| f.name().clone())
+            .collect::<Vec<_>>();
+        assert_eq!(names, vec!["column", "total", "max"]);
+    }
+
+    #[tokio::test]
+    async fn test_describe_propagates_errors() {
+        // succeeds when registered, then fails while describing
+        let calls = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let flaky: PreTransformFn = Arc::new(move |df: DataFrame| {
+            if calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst) > 0 {
+                anyhow::bail!("transform failed");
+            }
+            Ok(df)
+        });
+        let describer = DataFrameDescriber::try_new(create_test_dataframe())
+            .unwrap()
+            .apply_before_describe(flaky)
+            .unwrap();
+        assert!(describer.describe().await.is_err());
+        assert!(describer.describe_map().await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_top_percentile_out_of_range() {
+        assert!("top_150".parse::<DescribeMethod>().is_err());
+        assert!("top_100".parse::<DescribeMethod>().is_ok());
+        assert!(DescribeMethod::top_percentile(101).is_err());
+        assert!(DataFrameDescriber::try_new(create_test_dataframe())
+            .unwrap()
+            .with_top_percentiles(&[150])
+            .is_err());
+
+        // built directly, the variant fails the describe instead of panicking
+        let describer = DataFrameDescriber::try_new(create_test_dataframe())
+            .unwrap()
+            .with_methods(vec![DescribeMethod::TopPercentile(150)]);
+        assert!(describer.describe().await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_skewness_large_offset() {
+        let schema = Arc::new(Schema::new(vec![Field::new("x", DataType::Float64, false)]));
+        let batch = RecordBatch::try_new(
+            schema,
+            vec![Arc::new(Float64Array::from(vec![
+                1e8,
+                1e8 + 1.0,
+                1e8 + 2.0,
+                1e8 + 10.0,
+            ])) as ArrayRef],
+        )
+        .unwrap();
+        let df = SessionContext::new().read_batch(batch).unwrap();
+        let stats = DataFrameDescriber::try_new(df)
+            .unwrap()
+            .with_methods(vec![DescribeMethod::Skewness])
+            .describe_map()
+            .await
+            .unwrap();
+        // same as for [0, 1, 2, 10]
+        assert!((stats["x"]["skewness"].unwrap() - 1.0456).abs() < 1e-4);
+    }
+
+    #[tokio::test]
+    async fn test_null_helpers_weighted() {
+        let describer = weighted_test_describer();
+        let suite = describer.export_to_great_expectations_json().await.unwrap();
+        assert!(suite.contains("float_col"));
+        assert!(!suite.contains("int_col"));
+        describer.assert_no_nulls(&[]).await.unwrap();
+    }
+
+    #[test]
+    fn test_sort_key_follows_definition_order() {
+        // one of each variant, in definition order
+        let methods = [
+            DescribeMethod::Total,
+            DescribeMethod::NullTotal,
+            DescribeMethod::Mean,
+            DescribeMethod::Stddev,
+            DescribeMethod::StddevSample,
+            DescribeMethod::StddevPop,
+            DescribeMethod::Min,
+            DescribeMethod::Max,
+            DescribeMethod::Median,
+            DescribeMethod::Percentile(25),
+            DescribeMethod::PercentileF(0.5),
+            DescribeMethod::TopPercentile(1),
+            DescribeMethod::IsConstant,
+            DescribeMethod::WhitespacePaddedCount,
+            DescribeMethod::Skewness,
+            DescribeMethod::MaxDecimalPlaces,
+            DescribeMethod::MinAbs,
+            DescribeMethod::MaxAbs,
+            DescribeMethod::Gini,
+            DescribeMethod::GiniImpurity,
+            DescribeMethod::EmptyCount,
+            DescribeMethod::NonEmptyCount,
+            DescribeMethod::First,
+            DescribeMethod::Last,
+            DescribeMethod::MonotonicViolations,
+            DescribeMethod::HasMixedSign,
+            DescribeMethod::RangeUtilization,
+            DescribeMethod::MinBytes,
+            DescribeMethod::MaxBytes,
+        ];
+        assert!(methods
+            .windows(2)
+            .all(|pair| pair[0].sort_key() < pair[1].sort_key()));
+    }
+
+    #[test]
+    fn test_t_quantile() {
+        // reference values from t tables
+        assert_approx_eq!(t_quantile(0.975, 1.0), 12.7062, 1e-4);
+        assert_approx_eq!(t_quantile(0.975, 2.0), 4.3027, 1e-4);
+        assert_approx_eq!(t_quantile(0.975, 4.0), 2.7764, 1e-4);
+        assert_approx_eq!(t_quantile(0.995, 10.0), 3.1693, 1e-4);
+        assert_approx_eq!(t_quantile(0.975, 29.0), 2.0452, 1e-4);
+        assert_approx_eq!(t_quantile(0.025, 29.0), -2.0452, 1e-4);
+    }
+
+    #[tokio::test]
+    async fn test_kl_divergence_after_apply_before_describe() {
+        let describer = |values: Vec<f64>| {
+            let schema = Arc::new(Schema::new(vec![Field::new("x", DataType::Float64, true)]));
+            let batch = RecordBatch::try_new(
+                schema,
+                vec![Arc::new(Float64Array::from(values)) as ArrayRef],
+            )
+            .unwrap();
+            DataFrameDescriber::try_new(SessionContext::new().read_batch(batch).unwrap()).unwrap()
+        };
+        let negate: PreTransformFn =
+            Arc::new(|df: DataFrame| Ok(df.select(vec![(lit(0.0) - ident("x")).alias("x")])?));
+        let values = (1..=20).map(|i| i as f64).collect::<Vec<_>>();
+        let current = describer(values.clone())
+            .apply_before_describe(negate)
+            .unwrap();
+        let negated = describer(values.iter().map(|v| -v).collect());
+        let original = describer(values);
+
+        // the bins cover the transformed values, not the original ones
+        let kl = current.kl_divergence(&negated, "x", 10).await.unwrap();
+        assert_approx_eq!(kl, 0.0);
+        let psi = current
+            .population_stability_index(&negated, "x", 10)
+            .await
+            .unwrap();
+        assert_approx_eq!(psi, 0.0);
+        let kl = current.kl_divergence(&original, "x", 10).await.unwrap();
+        assert!(kl > 1.0, "{}", kl);
     }
 }
@@ -11,7 +11,6 @@ use datafusion::{
 };
 use std::sync::Arc;
 
-
 #[allow(unused)]
 pub struct DescribeDataFrame {
     df: DataFrame,
@@ -237,4 +236,3 @@ impl DescribeDataFrame {
         Ok(ret)
     }
 }
-
@@ -1,4 +1,4 @@
-mod describe;
+pub mod describe;
 mod df_describe;
 
 use arrow::{array::RecordBatch, util::pretty::pretty_format_batches};
@@ -17,7 +17,7 @@ use crate::{
     Backend, ReplDisplay,
 };
 
-use self::describe::DataFrameDescriber;
+pub use self::describe::{DataFrameDescriber, DescribeMethod};
 
 pub struct DataFusionBackend(SessionContext);
 
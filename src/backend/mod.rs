@@ -1,3 +1,3 @@
 mod fusion;
 
-pub use fusion::DataFusionBackend;
+pub use fusion::{describe, DataFrameDescriber, DataFusionBackend, DescribeMethod};
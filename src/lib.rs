@@ -9,6 +9,7 @@ use reedline_repl_rs::CallBackMap;
 use std::{ops::Deref, thread};
 use tokio::runtime::Runtime;
 
+pub use backend::{describe, DataFrameDescriber, DescribeMethod};
 pub use cli::ReplCommand;
 
 #[enum_dispatch]